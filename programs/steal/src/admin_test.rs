@@ -0,0 +1,132 @@
+// ============================================================================
+// ADMIN TESTS - timelocked changes and the two-step authority handover
+// ============================================================================
+
+use std::collections::BTreeMap;
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::{Pubkey, Result};
+
+use crate::constants::CHANGE_TIMELOCK_SECS;
+use crate::errors::StealError;
+use crate::logic::*;
+
+/// PendingChange accounts by id, with their eta
+/// Queueing inits the PDA; execute_change and cancel_change close it
+#[derive(Default)]
+struct Queue {
+    pending: BTreeMap<u64, i64>,
+    executed: Vec<u64>,
+}
+
+impl Queue {
+    fn queue(&mut self, id: u64, now: i64) -> Result<()> {
+        if self.pending.contains_key(&id) {
+            return Err(ErrorCode::AccountDiscriminatorAlreadySet.into());
+        }
+        self.pending.insert(id, change_eta(now));
+        Ok(())
+    }
+
+    fn execute(&mut self, id: u64, now: i64) -> Result<()> {
+        let eta = *self.pending.get(&id).ok_or(ErrorCode::AccountNotInitialized)?;
+        check_change_ready(now, eta)?;
+        self.pending.remove(&id);
+        self.executed.push(id);
+        Ok(())
+    }
+
+    fn cancel(&mut self, id: u64) -> Result<()> {
+        self.pending.remove(&id).ok_or(ErrorCode::AccountNotInitialized)?;
+        Ok(())
+    }
+}
+
+#[test]
+fn early_execute_is_rejected() {
+    let mut queue = Queue::default();
+    queue.queue(1, 1_000).unwrap();
+    assert_eq!(change_eta(1_000), 1_000 + CHANGE_TIMELOCK_SECS);
+
+    assert_eq!(
+        queue.execute(1, 1_000 + CHANGE_TIMELOCK_SECS - 1).unwrap_err(),
+        StealError::TimelockNotExpired.into()
+    );
+    // Still queued after the failed attempt, and runs once the delay is over
+    queue.execute(1, 1_000 + CHANGE_TIMELOCK_SECS).unwrap();
+    assert_eq!(queue.executed, vec![1]);
+}
+
+#[test]
+fn cancelled_change_never_executes() {
+    let mut queue = Queue::default();
+    queue.queue(7, 1_000).unwrap();
+    queue.cancel(7).unwrap();
+
+    assert_eq!(
+        queue.execute(7, 1_000 + CHANGE_TIMELOCK_SECS).unwrap_err(),
+        ErrorCode::AccountNotInitialized.into()
+    );
+    assert!(queue.executed.is_empty());
+}
+
+#[test]
+fn change_executes_at_most_once() {
+    let mut queue = Queue::default();
+    queue.queue(3, 1_000).unwrap();
+    // The id is taken while queued
+    assert!(queue.queue(3, 2_000).is_err());
+
+    let later = 1_000 + CHANGE_TIMELOCK_SECS;
+    queue.execute(3, later).unwrap();
+    assert_eq!(
+        queue.execute(3, later + 1).unwrap_err(),
+        ErrorCode::AccountNotInitialized.into()
+    );
+    assert_eq!(queue.executed, vec![3]);
+
+    // A re-queued id starts a fresh timelock
+    queue.queue(3, later).unwrap();
+    assert!(queue.execute(3, later + 1).is_err());
+}
+
+#[test]
+fn only_the_pending_authority_can_accept() {
+    let (old, new, stranger) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let mut authority = old;
+
+    // Nothing proposed: nobody can take over
+    let mut pending = Pubkey::default();
+    assert_eq!(
+        accept_pending_authority(&mut authority, &mut pending, Pubkey::default()).unwrap_err(),
+        StealError::InvalidPendingAuthority.into()
+    );
+
+    // propose_authority(new): neither a stranger nor the current authority can accept
+    pending = new;
+    for signer in [stranger, old] {
+        assert_eq!(
+            accept_pending_authority(&mut authority, &mut pending, signer).unwrap_err(),
+            StealError::InvalidPendingAuthority.into()
+        );
+    }
+    assert_eq!((authority, pending), (old, new));
+
+    accept_pending_authority(&mut authority, &mut pending, new).unwrap();
+    assert_eq!((authority, pending), (new, Pubkey::default()));
+}
+
+#[test]
+fn old_authority_loses_access_after_handover() {
+    let (old, new) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (mut authority, mut pending) = (old, new);
+    accept_pending_authority(&mut authority, &mut pending, new).unwrap();
+
+    // has_one = authority now only matches the new wallet
+    assert_ne!(authority, old);
+    assert_eq!(authority, new);
+    // The proposal is consumed: the old authority can't claim it back by accepting
+    assert!(accept_pending_authority(&mut authority, &mut pending, old).is_err());
+    assert!(accept_pending_authority(&mut authority, &mut pending, new).is_err());
+    assert_eq!(authority, new);
+}
@@ -0,0 +1,45 @@
+// ============================================================================
+// CIRCUIT BREAKER TESTS - pausing freezes the game clock, resuming shifts deadlines
+// ============================================================================
+
+use crate::logic::*;
+
+#[test]
+fn first_flag_trips_the_breaker() {
+    assert_eq!(pause_transition(false, true, 0, 5_000), PauseTransition::Trip);
+    // Nothing set before or after: just a flag update
+    assert_eq!(pause_transition(false, false, 0, 5_000), PauseTransition::Update);
+}
+
+#[test]
+fn game_clock_stops_while_paused() {
+    // Tripped at 5_000: every later read sees the pause instant
+    assert_eq!(game_clock(true, 5_000, 5_000), 5_000);
+    assert_eq!(game_clock(true, 5_000, 90_000), 5_000);
+    // Running: wall clock
+    assert_eq!(game_clock(false, 0, 90_000), 90_000);
+
+    // Switching from one flag to the other keeps the original pause instant
+    assert_eq!(pause_transition(true, true, 5_000, 7_000), PauseTransition::Update);
+}
+
+#[test]
+fn resume_shifts_deadlines_by_the_paused_time() {
+    let paused_for = match pause_transition(true, false, 5_000, 8_600) {
+        PauseTransition::Resume { paused_for } => paused_for,
+        other => panic!("expected a resume, got {:?}", other),
+    };
+    assert_eq!(paused_for, 3_600);
+
+    // A war ending 60s after the pause still has 60s left after the resume
+    let hit_a_lick_end_time = shift_deadline(5_060, paused_for);
+    assert_eq!(hit_a_lick_end_time - 8_600, 60);
+    // Unset deadlines stay unset
+    assert_eq!(shift_deadline(0, paused_for), 0);
+
+    // A clock that went backwards never shifts deadlines back
+    assert_eq!(
+        pause_transition(true, false, 5_000, 4_000),
+        PauseTransition::Resume { paused_for: 0 }
+    );
+}
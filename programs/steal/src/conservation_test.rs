@@ -0,0 +1,129 @@
+// ============================================================================
+// CONSERVATION TESTS - every permille split accounts for every lamport
+// ============================================================================
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::constants::*;
+use crate::logic::*;
+use crate::state::{Ruleset, StakeTier, StealQuote, StealSnapshot};
+
+#[test]
+fn run_it_up_split_sums_to_new_price() {
+    let rules = Ruleset::default();
+    for old_price in [START_PRICE, 1_234_567, 9_999_999_999, 3] {
+        let new_price = old_price * rules.run_it_up_rate / 100;
+        let (refund, dev, beast, yield_add, jackpot, dust) =
+            calculate_run_it_up_split(old_price, new_price, &rules);
+        assert_eq!(refund + dev + beast + yield_add + jackpot + dust, new_price);
+        // One lamport per floored share at most
+        assert!(dust < 4, "dust {} too large for {}", dust, old_price);
+    }
+}
+
+#[test]
+fn hit_a_lick_overhead_split_sums_to_price() {
+    let rules = Ruleset::default();
+    for price in [50_000_000, 123_456_789, 1_500_000_001] {
+        let refund = calculate_hit_a_lick_old_king_payout(price, &rules);
+        let (dev, beast, yield_add, jackpot, dust) = calculate_hit_a_lick_overhead_split(price, &rules);
+        assert_eq!(refund + dev + beast + yield_add + jackpot + dust, price);
+    }
+}
+
+#[test]
+fn end_payouts_sum_to_pot_normal_and_mega() {
+    let rules = Ruleset::default();
+    for pot in [10 * LAMPORTS_PER_SOL + 7, MEGA_POT_THRESHOLD + 999] {
+        let (w1, w2, w3, dev, beast, rollover, dust) = calculate_hit_a_lick_end_payouts(pot, &StakeTier::default(), &rules);
+        assert_eq!(w1 + w2 + w3 + dev + beast + rollover + dust, pot);
+    }
+}
+
+#[test]
+fn steal_routes_dust_into_pending_jackpot() {
+    let rules = Ruleset::default();
+    let mut game = new_game(&StakeTier::default(), &rules);
+    game.jackpot_balance = 100 * LAMPORTS_PER_SOL;
+    game.current_price = 1_234_567;
+    
+    let old_price = game.current_price;
+    let (_, _, _, yield_add, jackpot_add, dust) =
+        calculate_run_it_up_split(old_price, old_price * rules.run_it_up_rate / 100, &rules);
+    assert!(dust > 0);
+    
+    execute_steal(&mut game, &rules).unwrap();
+    assert_eq!(game.yield_pool, yield_add);
+    assert_eq!(game.pending_jackpot, jackpot_add + dust);
+    assert_eq!(game.dust, dust);
+}
+
+/// Every lamport of the entry (plus anything drawn from the pot) lands in one bucket
+fn assert_plan_conserves(quote: &StealQuote) {
+    assert_eq!(
+        quote.entry_cost + quote.refund_from_pot,
+        quote.old_king_refund + quote.dev_cut + quote.beast_cut
+            + quote.yield_pool_add + quote.jackpot_add + quote.dust
+    );
+}
+
+fn war_game(king_entry_price: u64) -> StealSnapshot {
+    StealSnapshot {
+        current_price: 300_000_001,
+        hit_a_lick_price: 300_000_001,
+        jackpot_balance: 10 * LAMPORTS_PER_SOL,
+        is_hit_a_lick_mode: true,
+        hit_a_lick_end_time: 100_000,
+        has_king: king_entry_price > 0,
+        current_king: if king_entry_price > 0 { Pubkey::new_unique() } else { Pubkey::default() },
+        king_entry_price,
+        currency_decimals: 9,
+        ..StealSnapshot::default()
+    }
+}
+
+#[test]
+fn plan_conserves_when_king_paid_more_than_the_war_price() {
+    let rules = Ruleset::default();
+    // Normie king paid the surcharge - covered by this entry's jackpot share
+    let normie_entry = calculate_hit_a_lick_entry_cost(300_000_001, false, &rules);
+    let quote = plan_steal(&war_game(normie_entry), 1, Pubkey::new_unique(), false, 10, &rules).unwrap();
+    assert_eq!(quote.old_king_refund, calculate_hit_a_lick_old_king_payout(normie_entry, &rules));
+    assert_eq!(quote.refund_from_pot, 0);
+    assert_plan_conserves(&quote);
+
+    // King who triggered the war paid the (much higher) Run It Up price - the pot covers the rest
+    let quote = plan_steal(&war_game(6 * LAMPORTS_PER_SOL), 1, Pubkey::new_unique(), true, 10, &rules).unwrap();
+    assert!(quote.refund_from_pot > 0);
+    assert_eq!(quote.jackpot_add, 0);
+    assert_plan_conserves(&quote);
+}
+
+#[test]
+fn plan_conserves_when_king_paid_less_than_the_war_price() {
+    let rules = Ruleset::default();
+    // War triggered by steal count while the Run It Up price was still below the war price
+    let quote = plan_steal(&war_game(250_000_003), 1, Pubkey::new_unique(), true, 10, &rules).unwrap();
+    assert_eq!(quote.refund_from_pot, 0);
+    assert_plan_conserves(&quote);
+}
+
+#[test]
+fn plan_books_refund_share_to_pot_without_a_king() {
+    let rules = Ruleset::default();
+    let quote = plan_steal(&war_game(0), 1, Pubkey::new_unique(), false, 10, &rules).unwrap();
+    assert_eq!(quote.old_king_refund, 0);
+    assert!(quote.jackpot_add >= calculate_hit_a_lick_old_king_payout(300_000_001, &rules));
+    assert_plan_conserves(&quote);
+
+    let growing = StealSnapshot {
+        current_price: 1_234_567,
+        round_end_time: 100_000,
+        min_growth_steals_for_war: 50,
+        currency_decimals: 9,
+        ..StealSnapshot::default()
+    };
+    let quote = plan_steal(&growing, 1, Pubkey::new_unique(), false, 10, &rules).unwrap();
+    assert!(quote.jackpot_add >= 1_234_567);
+    assert_plan_conserves(&quote);
+}
@@ -1,8 +1,13 @@
 // ============================================================================
 // STEAL CONSTANTS - A+ RULESET
 // ============================================================================
+// Economic knobs below are the defaults for GameParams (see state::Ruleset).
+// The program reads the on-chain ruleset; change it with set_params, not here.
+// START_PRICE, HIT_A_LICK_MIN/MAX and MEGA_POT_THRESHOLD are the default stake
+// tier (see state::StakeTier); each game instance carries its own.
 
 pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+pub const SOL_DECIMALS: u8 = 9;
 
 // ----------------------------------------------------------------------------
 // INITIAL STATE
@@ -95,6 +100,27 @@ pub const VIP_YIELD_MULTIPLIER: u64 = 2;                   // VIP gets 2x yield
 pub const VIP_NO_SURCHARGE: bool = true;                   // VIP pays no surcharge in Hit A Lick mode
 pub const NORMIE_RATE: u64 = 100;                          // Normie pays 100% of base price (1.0x)
 
+// ----------------------------------------------------------------------------
+// ADMIN
+// ----------------------------------------------------------------------------
+pub const CHANGE_TIMELOCK_SECS: i64 = 86_400;              // 24 hours between queueing and executing a change
+pub const EMERGENCY_REFUND_GRACE_SECS: u64 = 259_200;      // 3 days paused before the king can be refunded
+
+// ----------------------------------------------------------------------------
+// LEADERBOARDS
+// ----------------------------------------------------------------------------
+pub const LEADERBOARD_SIZE: usize = 10;                    // Wallets ranked on each season board
+pub const SEASON_PRIZE_SHARES: [u64; 3] = [50, 30, 20];   // % of the season prize pool for the top 3 net winners
+
+// ----------------------------------------------------------------------------
+// ROUND HISTORY
+// ----------------------------------------------------------------------------
+pub const ROUND_HISTORY_SIZE: usize = 32;                 // Finished rounds kept per game
+pub const WAR_TRIGGER_PRICE: u8 = 1;                      // Price reached the jackpot threshold
+pub const WAR_TRIGGER_STEALS: u8 = 2;                     // Round reached its minimum growth steals
+pub const PLAYER_FILTER_BYTES: usize = 32;                // 256-bit bloom filter behind unique_players
+pub const PLAYER_FILTER_HASHES: usize = 3;                // Filter bits set per wallet
+
 // ----------------------------------------------------------------------------
 // RENT EXEMPTION
 // ----------------------------------------------------------------------------
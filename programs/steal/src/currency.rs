@@ -0,0 +1,203 @@
+// ============================================================================
+// ENTRY CURRENCY - SOL or SPL token movements in and out of the vault
+// ============================================================================
+// SOL games keep lamports directly in the vault PDA. Token games keep the entry
+// mint in a token account owned by the vault PDA (its ATA) and move it with
+// transfer_checked, so the same call sites serve both kinds of table.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::errors::StealError;
+use crate::logic::is_vault_ata;
+use crate::state::GameAccount;
+
+/// Token-side accounts of an SPL-denominated game
+pub struct TokenCurrency<'a, 'info> {
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub token_vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+impl<'a, 'info> TokenCurrency<'a, 'info> {
+    /// Validate the token accounts passed for `game` - None for SOL games
+    /// Token games must pass the entry mint, the vault's ATA for it and its program
+    pub fn resolve(
+        game: &GameAccount,
+        vault: &Pubkey,
+        mint: Option<&'a InterfaceAccount<'info, Mint>>,
+        token_vault: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+        token_program: Option<&'a Interface<'info, TokenInterface>>,
+    ) -> Result<Option<Self>> {
+        if !game.is_token_game() {
+            return Ok(None);
+        }
+
+        let (mint, token_vault, token_program) = match (mint, token_vault, token_program) {
+            (Some(m), Some(v), Some(p)) => (m, v, p),
+            _ => return Err(StealError::MissingCurrencyAccount.into()),
+        };
+
+        require!(mint.key() == game.currency_mint, StealError::InvalidMint);
+        require!(
+            *mint.to_account_info().owner == token_program.key(),
+            StealError::InvalidMint
+        );
+        require!(token_vault.mint == game.currency_mint, StealError::InvalidMint);
+        require!(token_vault.owner == *vault, StealError::InvalidTokenAccountOwner);
+        // Any other vault-owned account would split the game's funds across accounts
+        require!(
+            is_vault_ata(&token_vault.key(), vault, &mint.key(), &token_program.key()),
+            StealError::InvalidTokenAccountOwner
+        );
+
+        Ok(Some(Self { mint, token_vault, token_program }))
+    }
+}
+
+/// Read a token account's balance straight from its data (fresh after CPIs)
+fn token_amount(account: &AccountInfo) -> Result<u64> {
+    let data = account.try_borrow_data()?;
+    Ok(TokenAccount::try_deserialize(&mut &data[..])?.amount)
+}
+
+/// Check a party's token account holds the entry mint and belongs to `wallet`
+fn check_party_account(
+    wallet: &Pubkey,
+    party_token: Option<&InterfaceAccount<TokenAccount>>,
+    token: &TokenCurrency,
+) -> Result<()> {
+    let party_token = party_token.ok_or(StealError::MissingCurrencyAccount)?;
+    require!(party_token.mint == token.mint.key(), StealError::InvalidMint);
+    require!(party_token.owner == *wallet, StealError::InvalidTokenAccountOwner);
+    Ok(())
+}
+
+/// Vault balance in the game's entry currency
+pub fn vault_balance(vault: &AccountInfo, token: Option<&TokenCurrency>) -> Result<u64> {
+    match token {
+        None => Ok(vault.lamports()),
+        Some(t) => token_amount(&t.token_vault.to_account_info()),
+    }
+}
+
+/// A wallet's balance in the game's entry currency
+pub fn party_balance(
+    wallet: &AccountInfo,
+    party_token: Option<&InterfaceAccount<TokenAccount>>,
+    token: Option<&TokenCurrency>,
+) -> Result<u64> {
+    match token {
+        None => Ok(wallet.lamports()),
+        Some(t) => {
+            check_party_account(wallet.key, party_token, t)?;
+            token_amount(&party_token.unwrap().to_account_info())
+        }
+    }
+}
+
+/// Move `amount` from the signing `payer` into the vault
+/// Token games reject mints that deliver less than `amount` (e.g. transfer fees),
+/// since every ledger field assumes the full amount arrived
+pub fn collect_into_vault<'info>(
+    payer: &AccountInfo<'info>,
+    payer_token: Option<&InterfaceAccount<'info, TokenAccount>>,
+    vault: &AccountInfo<'info>,
+    token: Option<&TokenCurrency<'_, 'info>>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    match token {
+        None => {
+            let ix = anchor_lang::solana_program::system_instruction::transfer(
+                payer.key,
+                vault.key,
+                amount,
+            );
+            anchor_lang::solana_program::program::invoke(
+                &ix,
+                &[payer.clone(), vault.clone(), system_program.clone()],
+            )?;
+        }
+        Some(t) => {
+            check_party_account(payer.key, payer_token, t)?;
+            let payer_token = payer_token.unwrap();
+            let vault_token = t.token_vault.to_account_info();
+            let before = token_amount(&vault_token)?;
+
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    t.token_program.to_account_info(),
+                    TransferChecked {
+                        from: payer_token.to_account_info(),
+                        mint: t.mint.to_account_info(),
+                        to: vault_token.clone(),
+                        authority: payer.clone(),
+                    },
+                ),
+                amount,
+                t.mint.decimals,
+            )?;
+
+            let received = token_amount(&vault_token)?.saturating_sub(before);
+            require!(received == amount, StealError::UnsupportedCurrencyMint);
+        }
+    }
+
+    Ok(())
+}
+
+/// Move `amount` out of the vault to `recipient` (signed by the vault PDA)
+/// SOL games pay the wallet; token games pay `recipient_token`, which must be
+/// the recipient's token account for the entry mint
+pub fn pay_from_vault<'info>(
+    vault: &AccountInfo<'info>,
+    vault_seeds: &[&[u8]],
+    recipient: &AccountInfo<'info>,
+    recipient_token: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token: Option<&TokenCurrency<'_, 'info>>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    match token {
+        None => {
+            let ix = anchor_lang::solana_program::system_instruction::transfer(
+                vault.key,
+                recipient.key,
+                amount,
+            );
+            anchor_lang::solana_program::program::invoke_signed(
+                &ix,
+                &[vault.clone(), recipient.clone(), system_program.clone()],
+                &[vault_seeds],
+            )?;
+        }
+        Some(t) => {
+            check_party_account(recipient.key, recipient_token, t)?;
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    t.token_program.to_account_info(),
+                    TransferChecked {
+                        from: t.token_vault.to_account_info(),
+                        mint: t.mint.to_account_info(),
+                        to: recipient_token.unwrap().to_account_info(),
+                        authority: vault.clone(),
+                    },
+                    &[vault_seeds],
+                ),
+                amount,
+                t.mint.decimals,
+            )?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+// ============================================================================
+// CURRENCY TESTS - decimal-aware amounts for SPL-token games
+// ============================================================================
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::{token, token_2022};
+
+use crate::constants::*;
+use crate::logic::*;
+use crate::state::Ruleset;
+
+#[test]
+fn sol_amounts_are_unchanged_for_sol_games() {
+    assert_eq!(scale_to_decimals(MIN_YIELD, SOL_DECIMALS), MIN_YIELD);
+    assert_eq!(scale_to_decimals(LAMPORTS_PER_SOL, SOL_DECIMALS), LAMPORTS_PER_SOL);
+}
+
+#[test]
+fn sol_amounts_scale_to_mint_decimals() {
+    // 1 SOL-denominated unit == 1 whole token
+    assert_eq!(scale_to_decimals(LAMPORTS_PER_SOL, 6), 1_000_000);
+    assert_eq!(scale_to_decimals(LAMPORTS_PER_SOL, 0), 1);
+    assert_eq!(scale_to_decimals(LAMPORTS_PER_SOL, 12), 1_000_000_000_000);
+    // Sub-unit remainders round down
+    assert_eq!(scale_to_decimals(999, 6), 0);
+    // Saturates instead of overflowing
+    assert_eq!(scale_to_decimals(u64::MAX, 18), u64::MAX);
+    assert_eq!(scale_to_decimals(1, 48), u64::MAX);
+    assert_eq!(scale_to_decimals(1, u8::MAX), u64::MAX);
+    assert_eq!(scale_to_decimals(0, u8::MAX), 0);
+}
+
+#[test]
+fn war_threshold_counts_whole_units_of_the_entry_currency() {
+    // 2 SOL and 2 whole 6-decimal tokens both need 35 + 2 * 15 growth steals
+    assert_eq!(compute_min_growth_steals_for_war(2 * LAMPORTS_PER_SOL, SOL_DECIMALS), 65);
+    assert_eq!(compute_min_growth_steals_for_war(2_000_000, 6), 65);
+    // A 6-decimal pot read as lamports would stay at the base
+    assert_eq!(compute_min_growth_steals_for_war(2_000_000, SOL_DECIMALS), GROWTH_STEALS_BASE);
+    // Large pots clamp, and absurd decimals neither panic nor add steals
+    assert_eq!(compute_min_growth_steals_for_war(u64::MAX, 0), MAX_GROWTH_STEALS_CLAMP);
+    assert_eq!(compute_min_growth_steals_for_war(u64::MAX, u8::MAX), MIN_GROWTH_STEALS_CLAMP);
+}
+
+#[test]
+fn token_vaults_carry_no_rent_reserve() {
+    let rules = Ruleset::default();
+    let sol = calculate_vault_liabilities(1_000, 0, 500, 0, RENT_EXEMPT_MIN).unwrap();
+    let token = calculate_vault_liabilities(1_000, 0, 500, 0, 0).unwrap();
+    assert_eq!(sol - token, RENT_EXEMPT_MIN);
+    // The SOL-denominated yield floor drops its three extra digits for a 6-decimal mint
+    assert_eq!(
+        scale_to_decimals(rules.min_yield, 6),
+        rules.min_yield / 1_000
+    );
+}
+
+#[test]
+fn token_vault_must_be_the_vault_ata() {
+    let (vault, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let ata = get_associated_token_address_with_program_id(&vault, &mint, &token::ID);
+    assert!(is_vault_ata(&ata, &vault, &mint, &token::ID));
+
+    // Another account, another owner's ATA, another mint or the other token program
+    assert!(!is_vault_ata(&Pubkey::new_unique(), &vault, &mint, &token::ID));
+    assert!(!is_vault_ata(&ata, &Pubkey::new_unique(), &mint, &token::ID));
+    assert!(!is_vault_ata(&ata, &vault, &Pubkey::new_unique(), &token::ID));
+    assert!(!is_vault_ata(&ata, &vault, &mint, &token_2022::ID));
+}
@@ -0,0 +1,57 @@
+// ============================================================================
+// EMERGENCY REFUND TESTS - a refunded king is made whole exactly once
+// ============================================================================
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::logic::*;
+use crate::state::VaultLedger;
+
+#[test]
+fn refund_credits_the_full_entry_from_the_pot() {
+    let paid = 400_000_000;
+    let mut ledger = VaultLedger {
+        jackpot_balance: 1_000_000_000,
+        pending_jackpot: 150_000_000,
+        ..Default::default()
+    };
+
+    // Run It Up and Hit A Lick alike: the whole entry becomes a claim, pending drawn first
+    assert_eq!(book_emergency_refund(&mut ledger, paid), paid);
+    assert_eq!(ledger.claims_outstanding, paid);
+    assert_eq!(ledger.pending_jackpot, 0);
+    assert_eq!(ledger.jackpot_balance, 750_000_000);
+}
+
+#[test]
+fn refund_is_capped_at_the_pot() {
+    let mut ledger = VaultLedger {
+        jackpot_balance: 100,
+        pending_jackpot: 20,
+        claims_outstanding: 5,
+        ..Default::default()
+    };
+
+    assert_eq!(book_emergency_refund(&mut ledger, 400), 120);
+    assert_eq!(ledger.claims_outstanding, 125);
+    assert_eq!(ledger.jackpot_balance + ledger.pending_jackpot, 0);
+}
+
+#[test]
+fn refunded_king_leaves_the_podium() {
+    let (a, b, c) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let mut kings = [a, b, c];
+    let mut count = 3;
+
+    remove_recent_king(&mut kings, &mut count, a);
+    assert_eq!(kings, [b, c, Pubkey::default()]);
+    assert_eq!(count, 2);
+
+    // Absent wallets leave the list alone
+    remove_recent_king(&mut kings, &mut count, a);
+    assert_eq!(count, 2);
+
+    // The next king goes to the front without resurrecting the refunded one
+    update_recent_kings(&mut kings, &mut count, Pubkey::default());
+    assert!(!kings.contains(&a));
+}
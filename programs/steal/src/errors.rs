@@ -40,4 +40,66 @@ pub enum StealError {
     WalletNotRentExempt,
     #[msg("Season has not started yet")]
     SeasonNotStarted,
+    #[msg("Signer is not the config authority")]
+    Unauthorized,
+    #[msg("Signer is not the pending config authority")]
+    InvalidPendingAuthority,
+    #[msg("Account is already on the current layout")]
+    AlreadyMigrated,
+    #[msg("Permille splits must sum to 1000 (at most 1000 for payouts)")]
+    InvalidSplit,
+    #[msg("Ruleset parameter out of range")]
+    InvalidParams,
+    #[msg("Timelock has not expired yet")]
+    TimelockNotExpired,
+    #[msg("Steals are paused")]
+    StealsPaused,
+    #[msg("Settlements are paused")]
+    SettlementsPaused,
+    #[msg("Game is not paused")]
+    NotPaused,
+    #[msg("Emergency refund grace period has not elapsed")]
+    GracePeriodNotElapsed,
+    #[msg("Game account uses an old layout - call migrate_game")]
+    GameNotMigrated,
+    #[msg("Vault lamports do not cover its liabilities")]
+    VaultUndercollateralized,
+    #[msg("Vault has no surplus to sweep")]
+    NoSurplus,
+    #[msg("Sweep amount exceeds the vault's surplus")]
+    SweepExceedsSurplus,
+    #[msg("Token game requires its currency mint, token vault and token accounts")]
+    MissingCurrencyAccount,
+    #[msg("Entry mint must deliver the exact amount transferred")]
+    UnsupportedCurrencyMint,
+    #[msg("Season has ended")]
+    SeasonEnded,
+    #[msg("Season has not ended yet")]
+    SeasonNotEnded,
+    #[msg("Season is already closed")]
+    SeasonClosed,
+    #[msg("Previous season must be closed first")]
+    PreviousSeasonOpen,
+    #[msg("Invalid season window or number")]
+    InvalidSeason,
+    #[msg("Leaderboard missing or not for this game's season")]
+    InvalidLeaderboard,
+    #[msg("Claim account for the dethroned king is required")]
+    MissingClaimAccount,
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+    #[msg("Entry cost is above the caller's max_cost")]
+    PriceExceeded,
+    #[msg("Round or mode changed since the caller's quote")]
+    StaleState,
+    #[msg("Crown is held by someone other than expected_king")]
+    KingChanged,
+    #[msg("Round history missing or not for this game")]
+    InvalidRoundHistory,
+    #[msg("Season winner account missing or out of order")]
+    InvalidSeasonWinner,
+    #[msg("Round counted in this season is still in progress")]
+    SeasonRoundInProgress,
+    #[msg("Game account is required to execute a config change")]
+    MissingGameAccount,
 }
@@ -0,0 +1,264 @@
+// ============================================================================
+// STEAL EVENTS
+// ============================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::state::{Ruleset, StealQuote};
+
+/// Emitted by audit_vault with the vault's solvency snapshot
+#[event]
+pub struct VaultAudited {
+    pub game: Pubkey,
+    pub vault_lamports: u64,
+    pub liabilities: u64,
+    pub surplus: u64,
+    pub deficit: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by sweep_surplus
+#[event]
+pub struct SurplusSwept {
+    pub game: Pubkey,
+    pub amount: u64,
+    pub to_jackpot: bool,
+    pub surplus_before: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by steal with the full breakdown of the entry
+#[event]
+pub struct CrownStolen {
+    pub game: Pubkey,
+    pub round: u64,
+    pub player: Pubkey,
+    /// Dethroned king (default if the crown was vacant or re-taken by the same wallet)
+    pub old_king: Pubkey,
+    pub entry_cost: u64,
+    /// Price before this steal (Run It Up base of the split)
+    pub old_price: u64,
+    /// Price the next stealer starts from
+    pub next_price: u64,
+    /// Credited to the old king's Claim account
+    pub refund: u64,
+    pub yield_paid: u64,
+    pub dev_cut: u64,
+    pub beast_cut: u64,
+    pub yield_pool_add: u64,
+    pub jackpot_add: u64,
+    /// Rounding dust booked to the next jackpot
+    pub dust: u64,
+    /// Part of the refund drawn from the pot (see StealQuote::refund_from_pot)
+    pub refund_from_pot: u64,
+    pub is_vip: bool,
+    pub hit_a_lick_mode: bool,
+    pub jackpot_balance: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by steal when the round flips into Hit A Lick mode
+#[event]
+pub struct HitALickTriggered {
+    pub game: Pubkey,
+    pub round: u64,
+    pub hit_a_lick_price: u64,
+    pub jackpot_balance: u64,
+    /// Trigger reason: price reached the threshold share of the jackpot...
+    pub price_trigger: bool,
+    /// ...and/or the round had enough growth steals
+    pub steals_trigger: bool,
+    pub growth_steals: u16,
+    pub timestamp: i64,
+}
+
+impl CrownStolen {
+    /// Event for an executed steal `quote`, with the game's price and jackpot after it
+    pub fn from_quote(
+        game: Pubkey,
+        player: Pubkey,
+        quote: &StealQuote,
+        old_price: u64,
+        next_price: u64,
+        jackpot_balance: u64,
+        timestamp: i64,
+    ) -> Self {
+        let paid_old_king = quote.old_king_refund.saturating_add(quote.old_king_yield) > 0;
+        Self {
+            game,
+            round: quote.round,
+            player,
+            old_king: if paid_old_king { quote.old_king } else { Pubkey::default() },
+            entry_cost: quote.entry_cost,
+            old_price,
+            next_price,
+            refund: quote.old_king_refund,
+            yield_paid: quote.old_king_yield,
+            dev_cut: quote.dev_cut,
+            beast_cut: quote.beast_cut,
+            yield_pool_add: quote.yield_pool_add,
+            jackpot_add: quote.jackpot_add,
+            dust: quote.dust,
+            refund_from_pot: quote.refund_from_pot,
+            is_vip: quote.is_vip,
+            hit_a_lick_mode: quote.hit_a_lick_mode || quote.triggers_hit_a_lick,
+            jackpot_balance,
+            timestamp,
+        }
+    }
+}
+
+impl HitALickTriggered {
+    /// Event for a steal `quote` that flips the round into Hit A Lick mode
+    pub fn from_quote(
+        game: Pubkey,
+        quote: &StealQuote,
+        jackpot_balance: u64,
+        growth_steals: u16,
+        timestamp: i64,
+    ) -> Self {
+        Self {
+            game,
+            round: quote.round,
+            hit_a_lick_price: quote.hit_a_lick_price,
+            jackpot_balance,
+            price_trigger: quote.price_trigger,
+            steals_trigger: quote.steals_trigger,
+            growth_steals,
+            timestamp,
+        }
+    }
+}
+
+/// Emitted by end_round with the podium recorded in the RoundPrize account
+#[event]
+pub struct RoundEnded {
+    pub game: Pubkey,
+    pub round: u64,
+    pub winners: [Pubkey; 3],
+    pub payouts: [u64; 3],
+    pub hit_a_lick_mode: bool,
+    pub mega: bool,
+    /// Pot at settlement
+    pub total_pot: u64,
+    /// Jackpot carried into the next round
+    pub next_jackpot: u64,
+    pub dust: u64,
+    /// Longest reign of the round, the final king's included
+    pub longest_reign_secs: u64,
+    pub longest_reign_wallet: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by reset_round for a round nobody played
+#[event]
+pub struct RoundReset {
+    pub game: Pubkey,
+    pub round: u64,
+    pub jackpot_balance: u64,
+    /// Longest reign of the round (a king refunded by emergency_refund)
+    pub longest_reign_secs: u64,
+    pub longest_reign_wallet: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by beast_buyback_burn
+#[event]
+pub struct BeastBurned {
+    pub game: Pubkey,
+    pub amount_sol: u64,
+    pub tokens_burned: u64,
+    pub total_burned: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by steal when the passed `old_king` is not the recorded king
+/// Nothing is paid to that account; the recorded king's refund and yield are
+/// credited to their Claim account as usual, to be withdrawn with `claim`
+#[event]
+pub struct OldKingPayoutSkipped {
+    pub game: Pubkey,
+    pub passed_old_king: Pubkey,
+    pub current_king: Pubkey,
+    pub credited: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a config change takes effect (execute_change, accept_authority)
+#[event]
+pub struct ConfigChanged {
+    /// Pending change id (None for an authority handover)
+    pub change_id: Option<u64>,
+    pub authority: Pubkey,
+    pub dev_wallet: Pubkey,
+    pub beast_wallet: Pubkey,
+    pub steal_mint: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a ruleset change is staged (execute_change) or takes effect at a
+/// game's round boundary (end_round, reset_round)
+#[event]
+pub struct ParamsChanged {
+    /// Pending change id (None when a staged ruleset is applied)
+    pub change_id: Option<u64>,
+    /// Game whose round boundary applied the ruleset (None when staged)
+    pub game: Option<Pubkey>,
+    /// Active ruleset version (already bumped when applied)
+    pub version: u64,
+    pub rules: Ruleset,
+    pub timestamp: i64,
+}
+
+/// Emitted by propose_authority (default pending_authority = handover cancelled)
+#[event]
+pub struct AuthorityProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by set_pause with the new flags
+#[event]
+pub struct PauseChanged {
+    pub game: Pubkey,
+    pub steals_paused: bool,
+    pub settlements_paused: bool,
+    /// Game clock freeze time (0 once resumed)
+    pub paused_at: i64,
+    /// Seconds the deadlines were shifted by on resume (0 otherwise)
+    pub shifted_secs: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted by emergency_refund
+#[event]
+pub struct EmergencyRefunded {
+    pub game: Pubkey,
+    pub round: u64,
+    pub king: Pubkey,
+    /// Credited to the king's Claim account (their full entry, drawn from the pot)
+    pub refund: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by claim when a wallet withdraws its credited refunds and yield
+#[event]
+pub struct PayoutClaimed {
+    pub game: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by claim_prize
+#[event]
+pub struct PrizeClaimed {
+    pub game: Pubkey,
+    pub round: u64,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    /// Places withdrawn by this claim
+    pub places: [bool; 3],
+    pub timestamp: i64,
+}
@@ -0,0 +1,87 @@
+// ============================================================================
+// EVENT TESTS - steal events carry the executed plan's breakdown
+// ============================================================================
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::constants::*;
+use crate::events::{CrownStolen, HitALickTriggered};
+use crate::logic::*;
+use crate::state::{Ruleset, StealSnapshot};
+
+fn reigning_game(king: Pubkey) -> StealSnapshot {
+    StealSnapshot {
+        current_price: START_PRICE,
+        has_king: true,
+        current_king: king,
+        king_since: 1_000,
+        king_entry_price: START_PRICE,
+        jackpot_balance: 10_000_000_000,
+        round_end_time: 100_000,
+        min_growth_steals_for_war: 50,
+        currency_decimals: 9,
+        ..StealSnapshot::default()
+    }
+}
+
+#[test]
+fn crown_stolen_carries_the_entry_breakdown() {
+    let rules = Ruleset::default();
+    let (king, player) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let game = Pubkey::new_unique();
+    let quote = plan_steal(&reigning_game(king), 4, player, false, 1_060, &rules).unwrap();
+
+    let event = CrownStolen::from_quote(game, player, &quote, START_PRICE, quote.next_price, 10_000_000_000, 1_060);
+    assert_eq!((event.game, event.round, event.player, event.old_king), (game, 4, player, king));
+    assert_eq!(event.entry_cost, START_PRICE * rules.run_it_up_rate / 100);
+    assert_eq!((event.old_price, event.next_price), (START_PRICE, event.entry_cost));
+    assert_eq!(event.refund, START_PRICE);
+    assert_eq!(event.yield_paid, quote.old_king_yield);
+    assert_eq!(
+        (event.dev_cut, event.beast_cut, event.yield_pool_add, event.jackpot_add, event.dust),
+        (quote.dev_cut, quote.beast_cut, quote.yield_pool_add, quote.jackpot_add, quote.dust)
+    );
+    assert!(event.dev_cut > 0 && event.beast_cut > 0 && event.jackpot_add > 0);
+    // Every lamport in (entry plus what the pot fronts) is accounted for
+    assert_eq!(
+        event.entry_cost + event.refund_from_pot,
+        event.refund + event.dev_cut + event.beast_cut + event.yield_pool_add + event.jackpot_add + event.dust
+    );
+    assert!(!event.is_vip && !event.hit_a_lick_mode);
+}
+
+#[test]
+fn crown_stolen_from_the_sitting_king_names_no_old_king() {
+    let rules = Ruleset::default();
+    let king = Pubkey::new_unique();
+    let quote = plan_steal(&reigning_game(king), 1, king, true, 1_060, &rules).unwrap();
+
+    let event = CrownStolen::from_quote(Pubkey::new_unique(), king, &quote, START_PRICE, quote.next_price, 0, 1_060);
+    assert_eq!(event.old_king, Pubkey::default());
+    assert_eq!(event.refund + event.yield_paid, 0);
+    assert!(event.is_vip);
+}
+
+#[test]
+fn hit_a_lick_triggered_carries_price_and_reasons() {
+    let rules = Ruleset::default();
+    let mut snapshot = reigning_game(Pubkey::new_unique());
+    snapshot.growth_steals = 49;
+    let quote = plan_steal(&snapshot, 2, Pubkey::new_unique(), false, 1_060, &rules).unwrap();
+    assert!(quote.triggers_hit_a_lick);
+
+    let game = Pubkey::new_unique();
+    let event = HitALickTriggered::from_quote(game, &quote, snapshot.jackpot_balance, 50, 1_060);
+    assert_eq!((event.game, event.round, event.timestamp), (game, 2, 1_060));
+    assert_eq!(
+        event.hit_a_lick_price,
+        calculate_hit_a_lick_price(snapshot.jackpot_balance, &snapshot.tier, &rules)
+    );
+    assert_eq!(event.jackpot_balance, 10_000_000_000);
+    assert_eq!(event.growth_steals, 50);
+    assert!(event.steals_trigger && !event.price_trigger);
+
+    // The steal that triggers the war already counts as played in it
+    let stolen = CrownStolen::from_quote(game, Pubkey::new_unique(), &quote, START_PRICE, event.hit_a_lick_price, 0, 1_060);
+    assert!(stolen.hit_a_lick_mode);
+}
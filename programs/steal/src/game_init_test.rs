@@ -0,0 +1,46 @@
+// ============================================================================
+// GAME INIT TESTS - only the config authority creates games, fully seeded
+// ============================================================================
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::constants::*;
+use crate::errors::StealError;
+use crate::logic::*;
+
+#[test]
+fn only_the_config_authority_creates_games() {
+    let authority = Pubkey::new_unique();
+    assert!(is_config_authority(&authority, &authority));
+    // Any other signer racing for the game PDA is rejected
+    assert!(!is_config_authority(&authority, &Pubkey::new_unique()));
+    // An unset authority matches nobody
+    assert!(!is_config_authority(&Pubkey::default(), &Pubkey::default()));
+}
+
+#[test]
+fn underfunded_seed_is_rejected() {
+    let (jackpot_seed, yield_seed) = (2 * LAMPORTS_PER_SOL, LAMPORTS_PER_SOL / 2);
+    let required = required_seed_balance(jackpot_seed, yield_seed, RENT_EXEMPT_MIN).unwrap();
+    assert_eq!(required, jackpot_seed + yield_seed + RENT_EXEMPT_MIN);
+
+    // The vault must end up holding both seeds and its rent
+    assert!(check_seed_funded(required, required).is_ok());
+    assert_eq!(
+        check_seed_funded(required - 1, required).unwrap_err(),
+        StealError::InsufficientVaultBalance.into()
+    );
+    // Seeds alone don't cover a SOL vault's rent
+    assert!(check_seed_funded(jackpot_seed + yield_seed, required).is_err());
+    // Token vaults keep no reserve
+    assert_eq!(required_seed_balance(jackpot_seed, yield_seed, 0).unwrap(), jackpot_seed + yield_seed);
+}
+
+#[test]
+fn seed_sum_overflow_is_rejected() {
+    assert_eq!(
+        required_seed_balance(u64::MAX, 1, 0).unwrap_err(),
+        StealError::Overflow.into()
+    );
+    assert!(required_seed_balance(u64::MAX - RENT_EXEMPT_MIN, 0, RENT_EXEMPT_MIN).is_ok());
+}
@@ -0,0 +1,72 @@
+// ============================================================================
+// INSTANCE TESTS - per-game PDA derivation and per-table ruleset snapshots
+// ============================================================================
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::logic::*;
+use crate::state::{game_id_seed, Ruleset, StealSnapshot};
+
+#[test]
+fn game_zero_keeps_the_original_pdas() {
+    let program_id = Pubkey::new_unique();
+    let (legacy_game, _) = Pubkey::find_program_address(&[b"game"], &program_id);
+    let (legacy_vault, _) = Pubkey::find_program_address(&[b"vault"], &program_id);
+    let seed = game_id_seed(0);
+    assert_eq!(Pubkey::find_program_address(&[b"game", &seed], &program_id).0, legacy_game);
+    assert_eq!(Pubkey::find_program_address(&[b"vault", &seed], &program_id).0, legacy_vault);
+}
+
+#[test]
+fn each_game_id_gets_its_own_game_and_vault() {
+    let program_id = Pubkey::new_unique();
+    let pda = |prefix: &[u8], id: u64| {
+        Pubkey::find_program_address(&[prefix, &game_id_seed(id)], &program_id).0
+    };
+    assert_ne!(pda(b"game", 0), pda(b"game", 1));
+    assert_ne!(pda(b"game", 1), pda(b"game", 2));
+    assert_ne!(pda(b"vault", 1), pda(b"vault", 2));
+    assert_ne!(pda(b"game", 1), pda(b"vault", 1));
+}
+
+/// A table mid-round in Run It Up with a sitting king
+fn growing_table() -> StealSnapshot {
+    StealSnapshot {
+        current_price: 100_000_000,
+        has_king: true,
+        current_king: Pubkey::new_unique(),
+        king_entry_price: 100_000_000,
+        round_end_time: 100_000,
+        min_growth_steals_for_war: 50,
+        currency_decimals: 9,
+        ..StealSnapshot::default()
+    }
+}
+
+#[test]
+fn staged_ruleset_reaches_each_table_at_its_own_round_boundary() {
+    // Shared GameParams, and each table's GameAccount::rules snapshot
+    let mut active = Ruleset::default();
+    let mut version = 1;
+    let staged = Ruleset { run_it_up_rate: 125, ..Ruleset::default() };
+    let mut has_staged = true;
+    let mut table_b = (active, version);
+
+    // Table A ends its round first: the staged ruleset is promoted and A snapshots it
+    assert!(promote_staged_rules(&mut active, &staged, &mut has_staged, &mut version));
+    let table_a = (active, version);
+
+    // Table B is still mid-round and keeps pricing with the rules it started with
+    let game = growing_table();
+    let quote_a = plan_steal(&game, 1, Pubkey::new_unique(), false, 10, &table_a.0).unwrap();
+    let quote_b = plan_steal(&game, 1, Pubkey::new_unique(), false, 10, &table_b.0).unwrap();
+    assert_eq!(quote_a.entry_cost, 125_000_000);
+    assert_eq!(quote_b.entry_cost, 112_000_000);
+    assert_eq!((table_a.1, table_b.1), (2, 1));
+
+    // Table B's boundary has nothing left to promote but picks up the active ruleset
+    assert!(!promote_staged_rules(&mut active, &staged, &mut has_staged, &mut version));
+    table_b = (active, version);
+    assert_eq!(table_b.0, table_a.0);
+    assert_eq!(table_b.1, 2);
+}
@@ -0,0 +1,99 @@
+// ============================================================================
+// LEADERBOARD TESTS - bounded top-N season boards
+// ============================================================================
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::constants::LEADERBOARD_SIZE;
+use crate::logic::*;
+use crate::state::LeaderboardEntry;
+
+fn board() -> [LeaderboardEntry; LEADERBOARD_SIZE] {
+    [LeaderboardEntry::default(); LEADERBOARD_SIZE]
+}
+
+#[test]
+fn ranked_wallets_accumulate_and_stay_sorted() {
+    let mut b = board();
+    let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    update_leaderboard(&mut b, alice, BoardUpdate::Add(5));
+    update_leaderboard(&mut b, bob, BoardUpdate::Add(3));
+    update_leaderboard(&mut b, bob, BoardUpdate::Add(4));
+
+    assert_eq!(b[0], LeaderboardEntry { wallet: bob, value: 7 });
+    assert_eq!(b[1], LeaderboardEntry { wallet: alice, value: 5 });
+    assert_eq!(b[2].wallet, Pubkey::default());
+
+    // A ranked wallet can go negative (net winnings) and sinks below the others
+    update_leaderboard(&mut b, bob, BoardUpdate::Add(-10));
+    assert_eq!(b[0].wallet, alice);
+    assert_eq!(b[1], LeaderboardEntry { wallet: bob, value: -3 });
+
+    // Max keeps the best value
+    update_leaderboard(&mut b, alice, BoardUpdate::Max(2));
+    assert_eq!(b[0].value, 5);
+}
+
+#[test]
+fn full_board_evicts_the_lowest_entry() {
+    let mut b = board();
+    for value in 1..=LEADERBOARD_SIZE as i64 {
+        update_leaderboard(&mut b, Pubkey::new_unique(), BoardUpdate::Add(value));
+    }
+
+    // Not better than the lowest entry (1): ignored
+    let newcomer = Pubkey::new_unique();
+    update_leaderboard(&mut b, newcomer, BoardUpdate::Add(1));
+    assert!(b.iter().all(|e| e.wallet != newcomer));
+
+    update_leaderboard(&mut b, newcomer, BoardUpdate::Add(2));
+    assert!(b.iter().any(|e| e.wallet == newcomer));
+    assert_eq!(b[LEADERBOARD_SIZE - 1].value, 2);
+    assert!(b.iter().all(|e| e.value != 1));
+}
+
+#[test]
+fn unranked_losses_are_not_recorded() {
+    let mut b = board();
+    update_leaderboard(&mut b, Pubkey::new_unique(), BoardUpdate::Add(-100));
+    update_leaderboard(&mut b, Pubkey::default(), BoardUpdate::Add(100));
+    assert!(b.iter().all(|e| e.wallet == Pubkey::default()));
+}
+
+#[test]
+fn break_even_king_does_not_climb() {
+    let mut b = board();
+    let (leader, king) = (Pubkey::new_unique(), Pubkey::new_unique());
+    update_leaderboard(&mut b, leader, BoardUpdate::Add(10));
+
+    // Refunded exactly what they paid: nothing to book
+    let paid = 400_000_000;
+    assert_eq!(reign_net_winnings(paid, paid), 0);
+    update_leaderboard(&mut b, king, BoardUpdate::Add(reign_net_winnings(paid, paid)));
+    assert!(b.iter().all(|e| e.wallet != king));
+
+    // Only the yield on top of the refund counts
+    update_leaderboard(&mut b, king, BoardUpdate::Add(reign_net_winnings(paid + 25, paid)));
+    assert_eq!(b[0], LeaderboardEntry { wallet: king, value: 25 });
+    assert_eq!(b[1], LeaderboardEntry { wallet: leader, value: 10 });
+}
+
+#[test]
+fn losing_reign_lowers_a_ranked_wallet() {
+    let mut b = board();
+    let (a, king) = (Pubkey::new_unique(), Pubkey::new_unique());
+    update_leaderboard(&mut b, a, BoardUpdate::Add(10));
+    update_leaderboard(&mut b, king, BoardUpdate::Add(30));
+
+    // A Hit A Lick refund below the entry is a loss
+    let delta = reign_net_winnings(360, 400);
+    assert_eq!(delta, -40);
+    update_leaderboard(&mut b, king, BoardUpdate::Add(delta));
+    assert_eq!(b[0].wallet, a);
+    assert_eq!(b[1], LeaderboardEntry { wallet: king, value: -10 });
+
+    // Saturates instead of wrapping
+    assert_eq!(reign_net_winnings(u64::MAX, 0), i64::MAX);
+    assert_eq!(reign_net_winnings(0, u64::MAX), i64::MIN);
+}
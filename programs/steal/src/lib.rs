@@ -6,6 +6,9 @@ pub mod constants;
 pub mod state;
 pub mod logic;
 pub mod errors;
+pub mod events;
+#[cfg(any(not(test), feature = "idl-build"))]
+pub mod currency;
 
 #[cfg(test)]
 mod tests;
@@ -19,41 +22,139 @@ mod time_attack_test;
 #[cfg(test)]
 mod pot_drainage_test;
 
+#[cfg(test)]
+mod params_test;
+
+#[cfg(test)]
+mod vault_audit_test;
+
+#[cfg(test)]
+mod conservation_test;
+
+#[cfg(test)]
+mod instances_test;
+
+#[cfg(test)]
+mod tiers_test;
+
+#[cfg(test)]
+mod currency_test;
+
+#[cfg(test)]
+mod vip_test;
+
+#[cfg(test)]
+mod seasons_test;
+
+#[cfg(test)]
+mod leaderboard_test;
+
+#[cfg(test)]
+mod player_stats_test;
+
+#[cfg(test)]
+mod prizes_test;
+
+#[cfg(test)]
+mod slippage_test;
+
+#[cfg(test)]
+mod quote_test;
+
+#[cfg(test)]
+mod round_history_test;
+
+#[cfg(test)]
+mod emergency_test;
+
+#[cfg(test)]
+mod events_test;
+
+#[cfg(test)]
+mod circuit_breaker_test;
+
+#[cfg(test)]
+mod admin_test;
+
+#[cfg(test)]
+mod migration_test;
+
+#[cfg(test)]
+mod game_init_test;
+
 // Anchor program setup (compiled for Solana builds and IDL generation, not for unit tests)
 #[cfg(any(not(test), feature = "idl-build"))]
 use anchor_lang::prelude::*;
 
 #[cfg(any(not(test), feature = "idl-build"))]
-use anchor_spl::token::{Token, TokenAccount};
-#[cfg(any(not(test), feature = "idl-build"))]
-use anchor_spl::token::spl_token;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
 
 #[cfg(any(not(test), feature = "idl-build"))]
 use crate::constants::*;
 
 #[cfg(any(not(test), feature = "idl-build"))]
-use crate::state::{GameAccount, GameConfig};
+use crate::state::{game_id_seed, ChangeKind, CircuitBreaker, GameAccount, GameConfig, Claim, GameParams, Leaderboard, PendingChange, PlayerAccount, RoundHistory, RoundPrize, RoundRecord, Ruleset, Season, StakeTier, StealQuote, SweepDestination, VaultAudit, GAME_LAYOUT_VERSION};
 
 #[cfg(any(not(test), feature = "idl-build"))]
 use crate::errors::StealError;
 
+#[cfg(any(not(test), feature = "idl-build"))]
+use crate::events::{
+    AuthorityProposed, BeastBurned, ConfigChanged, CrownStolen, EmergencyRefunded,
+    HitALickTriggered, OldKingPayoutSkipped, ParamsChanged, PauseChanged, PayoutClaimed,
+    PrizeClaimed, RoundEnded, RoundReset, SurplusSwept, VaultAudited,
+};
+
+#[cfg(any(not(test), feature = "idl-build"))]
+use crate::currency::{collect_into_vault, party_balance, pay_from_vault, vault_balance, TokenCurrency};
+
 #[cfg(any(not(test), feature = "idl-build"))]
 use crate::logic::{
-    calculate_hit_a_lick_price, 
-    calculate_yield_with_cap,
-    calculate_run_it_up_split,
-    calculate_hit_a_lick_entry_cost,
-    calculate_hit_a_lick_old_king_payout,
-    calculate_hit_a_lick_overhead_split,
-    calculate_hit_a_lick_end_payouts,
     update_recent_kings,
     compute_min_growth_steals_for_war,
-    is_vip
+    is_vip,
+    validate_ruleset,
+    calculate_vault_liabilities,
+    book_steal,
+    settle_round,
+    calculate_vault_surplus,
+    calculate_sweep_amount,
+    validate_stake_tier,
+    validate_season_window,
+    round_season_at,
+    season_round_settled,
+    reign_net_winnings,
+    update_leaderboard,
+    split_season_prize,
+    BoardUpdate,
+    advance_streak,
+    unclaimed_prize,
+    prizes_settled,
+    check_steal_slippage,
+    check_expected_king,
+    check_steal_open,
+    plan_steal,
+    hit_a_lick_threshold,
+    war_trigger_flags,
+    push_round_record,
+    note_player,
+    book_emergency_refund,
+    remove_recent_king,
+    pause_transition,
+    shift_deadline,
+    PauseTransition,
+    change_eta,
+    check_change_ready,
+    accept_pending_authority,
+    is_config_authority,
+    required_seed_balance,
+    check_seed_funded,
+    vip_account_balance
 };
 
 
-#[cfg(any(not(test), feature = "idl-build"))]
-declare_id!("CM9y2DreJSMqzoRRrLkWEZzTB9ve5D4gQHcPPxrw8mxg");
+// Also compiled for unit tests: GameAccount (#[account]) needs the program id
+anchor_lang::declare_id!("CM9y2DreJSMqzoRRrLkWEZzTB9ve5D4gQHcPPxrw8mxg");
 
 // Security.txt for verified builds - enables source verification on explorers
 #[cfg(not(feature = "no-entrypoint"))]
@@ -75,12 +176,20 @@ security_txt! {
 pub mod steal {
     use super::*;
 
-    /// Initialize a new game round
+    /// Initialize a new game instance (config authority only)
+    /// `game_id` 0 is the original table; every other id gets its own game and vault PDAs
+    /// `tier` fixes the table's stakes (starting price, Hit A Lick clamps, mega pot)
+    /// Funds the vault with jackpot_seed + yield_seed (plus rent) in the same
+    /// transaction, so the books and the vault lamports agree from the start
     pub fn initialize_game(
         ctx: Context<InitializeGame>,
+        game_id: u64,
         jackpot_seed: u64,
         yield_seed: u64,
+        tier: StakeTier,
     ) -> Result<()> {
+        validate_stake_tier(&tier)?;
+        
         // Validate dev and beast wallets are funded (rent-exempt)
         // This prevents InsufficientFundsForRent errors during steals
         require!(
@@ -92,15 +201,49 @@ pub mod steal {
             StealError::WalletNotRentExempt
         );
         
+        // Entry currency: the SPL mint if one is passed, SOL otherwise
+        if let Some(mint) = &ctx.accounts.currency_mint {
+            ctx.accounts.game.currency_mint = mint.key();
+            ctx.accounts.game.currency_decimals = mint.decimals;
+        } else {
+            ctx.accounts.game.currency_mint = Pubkey::default();
+            ctx.accounts.game.currency_decimals = SOL_DECIMALS;
+        }
+        let currency = TokenCurrency::resolve(
+            &ctx.accounts.game,
+            &ctx.accounts.game_vault.key(),
+            ctx.accounts.currency_mint.as_deref(),
+            ctx.accounts.token_vault.as_deref(),
+            ctx.accounts.currency_token_program.as_ref(),
+        )?;
+        let vault_info = ctx.accounts.game_vault.to_account_info();
+        
+        // Vault must hold both seeds on top of its own reserve (rent for SOL vaults)
+        let required_vault_balance = required_seed_balance(jackpot_seed, yield_seed, ctx.accounts.game.vault_reserve())?;
+        
+        // Top up whatever the vault doesn't already hold from the authority
+        let vault_shortfall = required_vault_balance.saturating_sub(vault_balance(&vault_info, currency.as_ref())?);
+        collect_into_vault(
+            &ctx.accounts.authority.to_account_info(),
+            ctx.accounts.authority_currency_account.as_deref(),
+            &vault_info,
+            currency.as_ref(),
+            &ctx.accounts.system_program.to_account_info(),
+            vault_shortfall,
+        )?;
+        
+        check_seed_funded(vault_balance(&vault_info, currency.as_ref())?, required_vault_balance)?;
+        
+        let rules = ctx.accounts.params.active;
         let game = &mut ctx.accounts.game;
         let clock = Clock::get()?;
         
-        game.current_price = START_PRICE;
+        game.current_price = tier.start_price;
         game.hit_a_lick_price = 0;
         game.jackpot_balance = jackpot_seed;
         game.pending_jackpot = 0;
         game.yield_pool = yield_seed;
-        game.round_end_time = clock.unix_timestamp + MAX_TIMER as i64;
+        game.round_end_time = clock.unix_timestamp + rules.max_timer as i64;
         game.hit_a_lick_end_time = 0;
         game.is_hit_a_lick_mode = false;
         game.current_king = Pubkey::default();
@@ -119,18 +262,39 @@ pub mod steal {
         game.recent_kings_count = 0;
         game.last_steal_wallet = Pubkey::default();
         game.last_steal_time = 0;
-        game.cooldown_seconds = HIT_A_LICK_COOLDOWN_SECS;
+        game.cooldown_seconds = rules.hit_a_lick_cooldown_secs;
         game.growth_steals = 0;
-        game.min_growth_steals_for_war = compute_min_growth_steals_for_war(jackpot_seed);
+        game.min_growth_steals_for_war = compute_min_growth_steals_for_war(jackpot_seed, game.currency_decimals);
         game.growth_hard_end_ts = clock.unix_timestamp + GROWTH_MAX_DURATION_SECS;
         game.king_was_vip = false;
         game.bump = ctx.bumps.game;
-        
-        // Note: Vault should be funded with at least jackpot_seed + yield_seed before first steal
-        // This is verified during the first steal operation with balance checks
-        
-        msg!("Game initialized! Price: {} lamports, Jackpot: {} lamports", 
-             game.current_price, game.jackpot_balance);
+        game.dust = 0;
+        game.game_id = game_id;
+        game.tier = tier;
+        game.season_number = 0;
+        game.round_season = 0;
+        game.claims_outstanding = 0;
+        game.round_start_ts = clock.unix_timestamp;
+        game.peak_price = 0;
+        game.war_trigger = 0;
+        game.clear_round_telemetry();
+        game.has_round_history = false;
+        game.rules = rules;
+        game.rules_version = ctx.accounts.params.version;
+        game.layout_version = GAME_LAYOUT_VERSION;
+        
+        // Every game starts with its circuit breaker, running
+        let breaker = &mut ctx.accounts.circuit_breaker;
+        breaker.game = game.key();
+        breaker.steals_paused = false;
+        breaker.settlements_paused = false;
+        breaker.paused_at = 0;
+        breaker.total_paused_secs = 0;
+        breaker.bump = ctx.bumps.circuit_breaker;
+        
+        msg!("Game {} initialized! Price: {}, Jackpot: {}, Vault: {} (currency: {})", 
+             game_id, game.current_price, game.jackpot_balance,
+             vault_balance(&vault_info, currency.as_ref())?, game.currency_mint);
         
         Ok(())
     }
@@ -154,6 +318,7 @@ pub mod steal {
         config.beast_wallet = ctx.accounts.beast_wallet.key();
         config.steal_mint = ctx.accounts.steal_mint.key();
         config.bump = ctx.bumps.config;
+        config.pending_authority = Pubkey::default();
         
         msg!("Config initialized! Authority: {}, Dev: {}, Beast: {}, Mint: {}", 
              config.authority, config.dev_wallet, config.beast_wallet, config.steal_mint);
@@ -161,413 +326,898 @@ pub mod steal {
         Ok(())
     }
 
-    /// Steal the crown (main game action)
-    pub fn steal(ctx: Context<Steal>) -> Result<()> {
+    /// Queue a rotation of the dev wallet, beast wallet and STEAL mint (authority only)
+    /// The change is recorded in a public PendingChange account and can be executed
+    /// by anyone once CHANGE_TIMELOCK_SECS have passed (see execute_change)
+    pub fn update_config(ctx: Context<UpdateConfig>, change_id: u64) -> Result<()> {
+        // Same funding requirement as initialize_config - transfers to an
+        // unfunded wallet would fail with InsufficientFundsForRent during steals
+        require!(
+            ctx.accounts.dev_wallet.lamports() >= RENT_EXEMPT_MIN,
+            StealError::WalletNotRentExempt
+        );
+        require!(
+            ctx.accounts.beast_wallet.lamports() >= RENT_EXEMPT_MIN,
+            StealError::WalletNotRentExempt
+        );
+        
         let clock = Clock::get()?;
-        let player = ctx.accounts.player.key();
-        let vault_bump = ctx.bumps.game_vault;
-        let vault_seeds: &[&[u8]] = &[b"vault", &[vault_bump]];
+        let pending = &mut ctx.accounts.pending_change;
+        pending.id = change_id;
+        pending.proposer = ctx.accounts.authority.key();
+        pending.change = ChangeKind::Config {
+            dev_wallet: ctx.accounts.dev_wallet.key(),
+            beast_wallet: ctx.accounts.beast_wallet.key(),
+            steal_mint: ctx.accounts.steal_mint.key(),
+        };
+        pending.created_at = clock.unix_timestamp;
+        pending.eta = change_eta(clock.unix_timestamp);
+        pending.bump = ctx.bumps.pending_change;
         
-        // Season check removed - field doesn't exist in on-chain account
-        let game = &ctx.accounts.game;
+        msg!("Config change {} queued! Dev: {}, Beast: {}, Mint: {}, ETA: {}", 
+             change_id, ctx.accounts.dev_wallet.key(), ctx.accounts.beast_wallet.key(),
+             ctx.accounts.steal_mint.key(), pending.eta);
         
-        // Validate and deserialize config account if provided (manual validation for backward compatibility)
-        // When config is None (null), we skip validation and use game account values
-        let config_data: Option<GameConfig> = if let Some(ref config_acc) = ctx.accounts.config {
-            // Derive expected config PDA
-            let (expected_config_pda, _bump) = Pubkey::find_program_address(
-                &[b"steal-config"],
-                ctx.program_id,
-            );
-            
-            // Verify the provided account matches the expected PDA
-            require!(
-                config_acc.key() == expected_config_pda,
-                StealError::InvalidAccount
-            );
-            
-            // Verify account is owned by this program
-            require!(
-                config_acc.owner == ctx.program_id,
-                StealError::InvalidAccount
-            );
-            
-            // Deserialize the config account (try_deserialize expects full data with discriminator)
-            let data = config_acc.data.borrow();
-            let config = GameConfig::try_deserialize(&mut &data[..])
-                .map_err(|_| StealError::InvalidAccount)?;
-            
-            Some(config)
-        } else {
-            None
-        };
+        Ok(())
+    }
+
+    /// Propose a new config authority (step 1 of 2)
+    /// The proposed authority must call accept_authority before anything changes.
+    /// Proposing Pubkey::default() cancels a pending handover.
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.pending_authority = new_authority;
         
-        // Get expected addresses from config if available, otherwise from game (backward compatibility)
-        // Do this before borrowing game as mutable
-        let (expected_dev_wallet, expected_beast_wallet, _steal_mint) = if let Some(config) = &config_data {
-            (config.dev_wallet, config.beast_wallet, config.steal_mint)
-        } else {
-            (ctx.accounts.game.dev_wallet, ctx.accounts.game.beast_wallet, ctx.accounts.game.steal_mint)
-        };
+        msg!("Authority handover proposed: {} -> {}", config.authority, new_authority);
+        emit_cpi!(AuthorityProposed {
+            authority: config.authority,
+            pending_authority: new_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
         
-        let game = &mut ctx.accounts.game;
+        Ok(())
+    }
+
+    /// Accept a proposed config authority (step 2 of 2)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
         
-        // Verify provided accounts match expected addresses
-        require!(
-            ctx.accounts.dev_wallet.key() == expected_dev_wallet,
-            StealError::InvalidAccount
-        );
+        // Pending authority must be set and must be the signer
+        let old_authority = config.authority;
+        let mut authority = config.authority;
+        let mut pending_authority = config.pending_authority;
+        accept_pending_authority(&mut authority, &mut pending_authority, ctx.accounts.new_authority.key())?;
+        config.authority = authority;
+        config.pending_authority = pending_authority;
+        
+        msg!("Authority handover complete: {} -> {}", old_authority, config.authority);
+        emit_cpi!(ConfigChanged {
+            change_id: None,
+            authority: config.authority,
+            dev_wallet: config.dev_wallet,
+            beast_wallet: config.beast_wallet,
+            steal_mint: config.steal_mint,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Grow a config account created before pending_authority existed
+    /// Anchor cannot deserialize the short legacy layout, so the account is
+    /// validated by hand, topped up for rent and zero-extended in place
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        let config_info = ctx.accounts.config.to_account_info();
+        
+        // Verify account is owned by this program
         require!(
-            ctx.accounts.beast_wallet.key() == expected_beast_wallet,
+            config_info.owner == ctx.program_id,
             StealError::InvalidAccount
         );
         
-        // Check if round has ended
-        if game.is_hit_a_lick_mode {
-            // Hit A Lick mode: allow steals even after timer if king hasn't held 3s yet
-            if clock.unix_timestamp >= game.hit_a_lick_end_time {
-                // Timer expired - check if king has held long enough
-                if game.has_king {
-                    let held_secs = (clock.unix_timestamp - game.king_since) as u64;
-                    if held_secs >= MIN_HIT_A_LICK_HOLD {
-                        // King has held 3s+, round is settleable - must call end_round
-                        return Err(StealError::RoundEnded.into());
-                    }
-                    // King hasn't held 3s yet - allow steal to continue (king is snipeable)
-                } else {
-                    // No king - round ended, must call end_round
-                    return Err(StealError::RoundEnded.into());
-                }
-            }
-            // Timer hasn't expired yet, or timer expired but king < 3s hold - allow steal
-            
-            // Rate limiting: check cooldown in Hit A Lick mode
-            if player == game.last_steal_wallet && game.last_steal_time > 0 {
-                let time_since_last_steal = clock.unix_timestamp - game.last_steal_time;
-                if time_since_last_steal < game.cooldown_seconds as i64 {
-                    return Err(StealError::RateLimitExceeded.into());
-                }
-            }
-        } else {
-            require!(
-                clock.unix_timestamp < game.round_end_time,
-                StealError::RoundEnded
-            );
-        }
-        
-        // Calculate entry cost based on token holdings
-        // Properly verify token account if provided
-        let token_balance: u64 = if let Some(ref token_acc) = ctx.accounts.player_token_account {
-            // Verify account is owned by Token Program
+        {
+            let data = config_info.data.borrow();
+            require!(data.len() < GameConfig::SIZE, StealError::AlreadyMigrated);
+            require!(data.len() >= GameConfig::LEGACY_SIZE, StealError::InvalidAccount);
             require!(
-                token_acc.owner == &spl_token::ID,
-                StealError::InvalidTokenAccount
+                &data[..8] == GameConfig::DISCRIMINATOR,
+                StealError::InvalidAccount
             );
             
-            // Deserialize token account to verify structure and get balance
-            let token_data = TokenAccount::try_deserialize(&mut &token_acc.data.borrow()[..])
-                .map_err(|_| StealError::InvalidTokenAccount)?;
-            
-            // Verify mint matches game's steal_mint
+            // Only the stored authority (first field after the discriminator) can migrate
+            let stored_authority = Pubkey::try_from(&data[8..40])
+                .map_err(|_| StealError::InvalidAccount)?;
             require!(
-                token_data.mint == game.steal_mint,
-                StealError::InvalidMint
+                stored_authority == ctx.accounts.authority.key(),
+                StealError::Unauthorized
             );
-            
-            // Verify token account owner matches player
-            require!(
-                token_data.owner == player,
-                StealError::InvalidTokenAccountOwner
+        }
+        
+        // Top up rent for the larger account
+        let rent_needed = Rent::get()?.minimum_balance(GameConfig::SIZE);
+        let shortfall = rent_needed.saturating_sub(config_info.lamports());
+        if shortfall > 0 {
+            let ix = anchor_lang::solana_program::system_instruction::transfer(
+                &ctx.accounts.authority.key(),
+                &config_info.key(),
+                shortfall,
             );
-            
-            token_data.amount
-        } else {
-            0
-        };
+            anchor_lang::solana_program::program::invoke(
+                &ix,
+                &[
+                    ctx.accounts.authority.to_account_info(),
+                    config_info.clone(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+        }
         
-        let is_vip = is_vip(token_balance);
+        // Zero-extend: new pending_authority reads as Pubkey::default()
+        config_info.resize(GameConfig::SIZE)?;
         
-        // Calculate entry cost based on mode
-        // IMPORTANT: This is calculated BEFORE any state updates, so it correctly captures
-        // the price the player will pay even if this steal triggers HAL transition
-        let entry_cost = if game.is_hit_a_lick_mode {
-            // Hit A Lick mode: VIP pays no surcharge, normie pays 12% surcharge
-            calculate_hit_a_lick_entry_cost(game.hit_a_lick_price, is_vip)
-        } else {
-            // Run It Up mode: pay new price (current * 1.12) - VIP and Normie pay same price
-            // VIP benefits are in yield, not price
-            game.current_price
-                .checked_mul(RUN_IT_UP_RATE)
-                .and_then(|v| v.checked_div(100))
-                .unwrap_or(u64::MAX)
-        };
+        msg!("Config migrated to {} bytes", GameConfig::SIZE);
         
-        // Safety check: entry_cost should never be 0 if player is paying
-        require!(
-            entry_cost > 0,
-            StealError::InvalidAccount
-        );
+        Ok(())
+    }
+
+    /// Initialize the ruleset account with the A+ defaults from constants.rs
+    pub fn initialize_params(ctx: Context<InitializeParams>) -> Result<()> {
+        let params = &mut ctx.accounts.params;
+        params.version = 1;
+        params.active = Ruleset::default();
+        params.staged = Ruleset::default();
+        params.has_staged = false;
+        params.bump = ctx.bumps.params;
         
-        // Verify player has enough SOL
-        require!(
-            ctx.accounts.player.lamports() >= entry_cost,
-            StealError::InsufficientFunds
-        );
+        msg!("Params initialized! Version: {}", params.version);
         
-        // Store old_price before any updates (needed for calculations)
-        let old_price = game.current_price;
+        Ok(())
+    }
+
+    /// Queue a new ruleset (authority only)
+    /// Once the timelock expires, execute_change stages it; the staged ruleset then
+    /// takes effect at the next round boundary (end_round/reset_round), never mid-round
+    pub fn set_params(ctx: Context<SetParams>, change_id: u64, rules: Ruleset) -> Result<()> {
+        validate_ruleset(&rules)?;
         
-        // Calculate new_price (what the new player will pay)
-        // In Run It Up: new_price = old_price * 1.12 (same for VIP and normie)
-        // In Hit A Lick: new_price = entry_cost (hit_a_lick_price + surcharge, or just hit_a_lick_price for VIP)
-        let new_price = entry_cost;
-        
-        // Calculate all required payouts BEFORE making any transfers
-        // This allows us to verify vault has sufficient balance
-        let mut total_required_payouts = 0u64;
-        let mut old_king_payout = 0u64;
-        let mut yield_payout = 0u64;
-        
-        if game.has_king && game.current_king != player {
-            // Check if old_king account matches the actual current king
-            // If mismatch (race condition), we skip the payout - actual king gets paid when next person steals from them
-            // This prevents transaction failures during high contention
-            let old_king_matches = ctx.accounts.old_king.key() == game.current_king;
-            
-            // Only calculate and pay the old king if accounts match
-            // Race condition (mismatch): skip payout - actual king gets paid when next person steals from them
-            if old_king_matches {
-                let hold_time = (clock.unix_timestamp - game.king_since) as u64;
+        let clock = Clock::get()?;
+        let pending = &mut ctx.accounts.pending_change;
+        pending.id = change_id;
+        pending.proposer = ctx.accounts.authority.key();
+        pending.change = ChangeKind::Params { rules };
+        pending.created_at = clock.unix_timestamp;
+        pending.eta = change_eta(clock.unix_timestamp);
+        pending.bump = ctx.bumps.pending_change;
+        
+        msg!("Params change {} queued! ETA: {}", change_id, pending.eta);
+        
+        Ok(())
+    }
+
+    /// Apply a queued change once its timelock has expired (permissionless)
+    /// Config changes are mirrored into the legacy copies on the game account, which
+    /// `steal` and `end_round` fall back to when no config account is passed
+    pub fn execute_change(ctx: Context<ExecuteChange>) -> Result<()> {
+        let clock = Clock::get()?;
+        let pending = &ctx.accounts.pending_change;
+        
+        check_change_ready(clock.unix_timestamp, pending.eta)?;
+        
+        match pending.change {
+            ChangeKind::Config { dev_wallet, beast_wallet, steal_mint } => {
+                let config = &mut ctx.accounts.config;
+                config.dev_wallet = dev_wallet;
+                config.beast_wallet = beast_wallet;
+                config.steal_mint = steal_mint;
+                
+                // Mirror into the game account (backward compatibility for callers without config)
+                // Other instances pick the change up via sync_config
+                let game = ctx.accounts.game.as_mut().ok_or(StealError::MissingGameAccount)?;
+                game.dev_wallet = dev_wallet;
+                game.beast_wallet = beast_wallet;
+                game.steal_mint = steal_mint;
                 
-                // Calculate refund and yield based on mode
-                let refund = if game.is_hit_a_lick_mode {
-                    // Hit A Lick mode: 90% refund of what the player actually paid
-                    // This correctly handles transition case where player paid Run It Up price
-                    // CRITICAL: king_entry_price contains what the player actually paid, which may be
-                    // the Run It Up price if they triggered the HAL transition
-                    let calculated_refund = calculate_hit_a_lick_old_king_payout(game.king_entry_price);
-                    // Safety check: refund should never be 0 if player paid something (king_entry_price > 0)
-                    // If king_entry_price is 0, that's also an error (player should have paid something)
-                    require!(
-                        calculated_refund > 0 && game.king_entry_price > 0,
-                        StealError::InvalidAccount
-                    );
-                    calculated_refund
-                } else {
-                    // Run It Up mode: FULL REFUND of what they paid
-                    game.king_entry_price
-                };
+                msg!("Config change {} executed! Dev: {}, Beast: {}, Mint: {}", 
+                     pending.id, dev_wallet, beast_wallet, steal_mint);
+                emit_cpi!(ConfigChanged {
+                    change_id: Some(pending.id),
+                    authority: config.authority,
+                    dev_wallet,
+                    beast_wallet,
+                    steal_mint,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+            ChangeKind::Params { rules } => {
+                // Re-check: invariants may have been tightened by a program upgrade since queueing
+                validate_ruleset(&rules)?;
                 
-                // Calculate yield (run it up mode only, hit a lick mode has no yield)
-                yield_payout = if game.is_hit_a_lick_mode {
-                    0
-                } else {
-                    // Use tracked VIP status for yield calculation
-                    let old_king_was_vip = game.king_was_vip;
-                    let uncapped_yield = calculate_yield_with_cap(
-                        game.yield_pool,
-                        game.king_entry_price,
-                        new_price,
-                        hold_time,
-                        old_king_was_vip
-                    );
-                    uncapped_yield.max(MIN_YIELD.min(game.yield_pool))
-                };
+                let params = &mut ctx.accounts.params;
+                params.staged = rules;
+                params.has_staged = true;
                 
-                // Total: FULL refund + yield
-                old_king_payout = refund.saturating_add(yield_payout);
-                total_required_payouts = total_required_payouts.saturating_add(old_king_payout);
-            } else {
-                // SECURITY NOTE: This is intentional race condition handling.
-                // During high contention, multiple steals may be submitted with stale old_king.
-                // Rather than failing the transaction (poor UX), we skip the payout.
-                // The actual current king (game.current_king) will be paid on the next steal.
-                // This design prioritizes availability over strict consistency.
-                msg!("Race condition detected: old_king={}, current_king={}, skipping payout (actual king paid on next steal)",
-                     ctx.accounts.old_king.key(), game.current_king);
+                msg!("Params change {} executed! Staged for next round (active version: {})", 
+                     pending.id, params.version);
+                emit_cpi!(ParamsChanged {
+                    change_id: Some(pending.id),
+                    game: None,
+                    version: params.version,
+                    rules,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+        }
+        
+        Ok(())
+    }
+
+    /// Cancel a queued change before it is executed (authority only)
+    pub fn cancel_change(ctx: Context<CancelChange>) -> Result<()> {
+        msg!("Change {} cancelled", ctx.accounts.pending_change.id);
+        
+        Ok(())
+    }
+
+    /// Create the circuit breaker for a game created before initialize_game made one (authority only)
+    pub fn initialize_circuit_breaker(ctx: Context<InitializeCircuitBreaker>) -> Result<()> {
+        let breaker = &mut ctx.accounts.circuit_breaker;
+        breaker.game = ctx.accounts.game.key();
+        breaker.steals_paused = false;
+        breaker.settlements_paused = false;
+        breaker.paused_at = 0;
+        breaker.total_paused_secs = 0;
+        breaker.bump = ctx.bumps.circuit_breaker;
+        
+        msg!("Circuit breaker initialized for game {}", breaker.game);
+        
+        Ok(())
+    }
+
+    /// Pause or resume steals and settlements (authority only)
+    /// While any flag is set the game clock is frozen; when the last flag is cleared,
+    /// every round deadline is shifted forward by the paused duration so the sitting
+    /// king loses no time to the pause
+    pub fn set_pause(
+        ctx: Context<SetPause>,
+        steals_paused: bool,
+        settlements_paused: bool,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let breaker = &mut ctx.accounts.circuit_breaker;
+        let game = &mut ctx.accounts.game;
+        
+        let was_paused = breaker.is_paused();
+        breaker.steals_paused = steals_paused;
+        breaker.settlements_paused = settlements_paused;
+        let mut shifted_secs = 0;
+        
+        match pause_transition(was_paused, breaker.is_paused(), breaker.paused_at, clock.unix_timestamp) {
+            PauseTransition::Trip => {
+                // Freeze the game clock
+                breaker.paused_at = clock.unix_timestamp;
+                msg!("Game paused at {} (steals: {}, settlements: {})", 
+                     breaker.paused_at, steals_paused, settlements_paused);
+            }
+            PauseTransition::Resume { paused_for } => {
+                // Resume: shift deadlines forward by the time spent paused
+                shifted_secs = paused_for;
+                game.round_end_time = game.round_end_time.saturating_add(paused_for);
+                game.hit_a_lick_end_time = shift_deadline(game.hit_a_lick_end_time, paused_for);
+                game.growth_hard_end_ts = shift_deadline(game.growth_hard_end_ts, paused_for);
+                // Hold time and cooldown are measured on the game clock too
+                if game.has_king {
+                    game.king_since = game.king_since.saturating_add(paused_for);
+                }
+                game.last_steal_time = shift_deadline(game.last_steal_time, paused_for);
+                
+                breaker.total_paused_secs = breaker.total_paused_secs.saturating_add(paused_for as u64);
+                breaker.paused_at = 0;
+                msg!("Game resumed after {} seconds, deadlines shifted", paused_for);
             }
+            PauseTransition::Update => {
+                msg!("Pause flags updated (steals: {}, settlements: {})", steals_paused, settlements_paused);
+            }
+        }
+        emit_cpi!(PauseChanged {
+            game: game.key(),
+            steals_paused,
+            settlements_paused,
+            paused_at: breaker.paused_at,
+            shifted_secs,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Refund the sitting king after a prolonged pause (permissionless)
+    /// Once the game has been paused for emergency_refund_grace_secs, anyone can credit
+    /// the king's full king_entry_price to their Claim account (drawn from the pot, like
+    /// every refund of the sitting king); the king then no longer holds the crown
+    pub fn emergency_refund(ctx: Context<EmergencyRefund>) -> Result<()> {
+        let clock = Clock::get()?;
+        let rules = ctx.accounts.game.rules;
+        let breaker = &ctx.accounts.circuit_breaker;
+        let game = &mut ctx.accounts.game;
+        
+        require!(breaker.is_paused(), StealError::NotPaused);
+        let refund_after = breaker.paused_at
+            .saturating_add(rules.emergency_refund_grace_secs as i64);
+        require!(
+            clock.unix_timestamp >= refund_after,
+            StealError::GracePeriodNotElapsed
+        );
+        require!(game.has_king, StealError::NoKing);
+        
+        let king = game.current_king;
+        let mut ledger = game.ledger();
+        let refund = book_emergency_refund(&mut ledger, game.king_entry_price);
+        game.set_ledger(&ledger);
+        
+        // Credit the king - the funds stay in the vault until they `claim`
+        let claim = &mut ctx.accounts.king_claim;
+        if claim.wallet == Pubkey::default() {
+            // Created by this refund
+            claim.game = game.key();
+            claim.wallet = king;
+            claim.bump = ctx.bumps.king_claim;
         }
+        claim.amount = claim.amount.saturating_add(refund);
+        claim.total_credited = claim.total_credited.saturating_add(refund);
+        
+        msg!("EMERGENCY REFUND! {} credited {} after {} seconds paused (claimable: {})", 
+             king, refund, clock.unix_timestamp - breaker.paused_at, claim.amount);
+        emit_cpi!(EmergencyRefunded {
+            game: game.key(),
+            round: game.round,
+            king,
+            refund,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        // The refunded king's reign ends here, on the frozen game clock
+        let reign = breaker.game_clock(clock.unix_timestamp).saturating_sub(game.king_since).max(0) as u64;
+        game.note_reign(king, reign);
+        
+        // Made whole - the refunded king no longer holds a podium place
+        let mut recent_kings = game.recent_kings;
+        let mut count = game.recent_kings_count;
+        remove_recent_king(&mut recent_kings, &mut count, king);
+        game.recent_kings = recent_kings;
+        game.recent_kings_count = count;
+        
+        // Crown is vacated - the round continues (or resets) without a king after resume
+        game.current_king = Pubkey::default();
+        game.king_since = 0;
+        game.king_entry_price = 0;
+        game.king_base_price = 0;
+        game.king_was_vip = false;
+        game.has_king = false;
+        
+        Ok(())
+    }
+
+    /// Copy the config's dev/beast wallets and $STEAL mint into a game instance (permissionless)
+    /// execute_change mirrors into a single game; this brings every other instance in line
+    pub fn sync_config(ctx: Context<SyncConfig>) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let game = &mut ctx.accounts.game;
+        
+        game.dev_wallet = config.dev_wallet;
+        game.beast_wallet = config.beast_wallet;
+        game.steal_mint = config.steal_mint;
+        
+        msg!("Game {} synced with config! Dev: {}, Beast: {}, Mint: {}", 
+             game.game_id, game.dev_wallet, game.beast_wallet, game.steal_mint);
+        
+        Ok(())
+    }
+
+    /// Open the next season of a game with its steal window (config authority only)
+    /// Seasons are numbered 1, 2, ... and the previous one must be closed first
+    pub fn start_season(
+        ctx: Context<StartSeason>,
+        number: u64,
+        start_ts: i64,
+        end_ts: i64,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let game = &mut ctx.accounts.game;
+        require!(
+            number == game.season_number.saturating_add(1),
+            StealError::InvalidSeason
+        );
         
-        // Calculate overhead payments (dev/beast cuts) BEFORE state updates
-        // Also calculate yield_add and jackpot_add for pool updates
-        let (overhead_dev_cut, overhead_beast_cut, overhead_yield_add, overhead_jackpot_add) = if game.is_hit_a_lick_mode {
-            let (d, b, y, j) = calculate_hit_a_lick_overhead_split(game.hit_a_lick_price);
-            // Add surcharge to jackpot (entry_cost - hit_a_lick_price for normies, 0 for VIPs)
-            let surcharge = entry_cost.saturating_sub(game.hit_a_lick_price);
-            (d, b, y, j.saturating_add(surcharge))
+        // close_season closes the Season account, and only after its end - so an emptied
+        // previous season has ended, and the next one may start from now on
+        let prev_end_ts = if game.season_number > 0 {
+            let previous = ctx.accounts.previous_season.as_ref().ok_or(StealError::PreviousSeasonOpen)?;
+            require!(previous.data_is_empty(), StealError::PreviousSeasonOpen);
+            clock.unix_timestamp
         } else {
-            // Calculate taxes on profit_delta (new_price - old_price)
-            let (_, d, b, y, j) = calculate_run_it_up_split(old_price, new_price);
-            (d, b, y, j)
+            0
         };
-        total_required_payouts = total_required_payouts.saturating_add(overhead_dev_cut).saturating_add(overhead_beast_cut);
+        validate_season_window(start_ts, end_ts, prev_end_ts)?;
+        
+        let season = &mut ctx.accounts.season;
+        season.game = game.key();
+        season.number = number;
+        season.start_ts = start_ts;
+        season.end_ts = end_ts;
+        season.prize_pool = 0;
+        season.first_round = game.round;
+        season.last_round = 0;
+        season.closed = false;
+        season.closed_at = 0;
+        season.bump = ctx.bumps.season;
+        
+        game.season_number = number;
+        
+        let mut board = ctx.accounts.leaderboard.load_init()?;
+        board.game = game.key();
+        board.season = number;
+        board.bump = ctx.bumps.leaderboard;
+        
+        msg!("Season {} of game {} scheduled: {} -> {}", number, game.game_id, start_ts, end_ts);
+        
+        Ok(())
+    }
+
+    /// Add lamports to a season's prize pool (anyone)
+    pub fn fund_season(ctx: Context<FundSeason>, amount: u64) -> Result<()> {
+        require!(!ctx.accounts.season.closed, StealError::SeasonClosed);
         
-        // Collect payment from player FIRST (safer transaction order)
         let ix = anchor_lang::solana_program::system_instruction::transfer(
-            &ctx.accounts.player.key(),
-            &ctx.accounts.game_vault.key(),
-            entry_cost,
+            &ctx.accounts.funder.key(),
+            &ctx.accounts.season.key(),
+            amount,
         );
         anchor_lang::solana_program::program::invoke(
             &ix,
             &[
-                ctx.accounts.player.to_account_info(),
-                ctx.accounts.game_vault.to_account_info(),
+                ctx.accounts.funder.to_account_info(),
+                ctx.accounts.season.to_account_info(),
                 ctx.accounts.system_program.to_account_info(),
             ],
         )?;
         
-        // Check vault balance AFTER collecting entry but BEFORE paying out
-        let vault_balance = ctx.accounts.game_vault.lamports();
+        let season = &mut ctx.accounts.season;
+        season.prize_pool = season.prize_pool
+            .checked_add(amount)
+            .ok_or(StealError::Overflow)?;
+        
+        msg!("Season {} prize pool: {} lamports (+{})", season.number, season.prize_pool, amount);
+        
+        Ok(())
+    }
+
+    /// Finalize a season once its window has passed (permissionless)
+    /// Pays the prize pool to the top net winners of the season leaderboard, passed as
+    /// writable remaining accounts in place order; the rest returns to the config authority
+    pub fn close_season<'info>(ctx: Context<'_, '_, '_, 'info, CloseSeason<'info>>) -> Result<()> {
+        let clock = Clock::get()?;
+        let season = &ctx.accounts.season;
+        let game = &ctx.accounts.game;
+        
+        require!(!season.closed, StealError::SeasonClosed);
+        require!(clock.unix_timestamp >= season.end_ts, StealError::SeasonNotEnded);
+        // The board is only final once the last round counted in the season has settled
         require!(
-            vault_balance >= total_required_payouts,
-            StealError::InsufficientVaultBalance
+            season_round_settled(season.number, game.round_season, game.total_steals),
+            StealError::SeasonRoundInProgress
         );
         
-        // Pay the old king if there is one
-        if old_king_payout > 0 {
-            // Transfer to old king using CPI
-            let transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
-                &ctx.accounts.game_vault.key(),
-                &ctx.accounts.old_king.key(),
-                old_king_payout,
+        let (winners, amounts, remainder) = {
+            let board = ctx.accounts.leaderboard.load()?;
+            split_season_prize(season.prize_pool, &board.net_winnings)
+        };
+        
+        let season_info = season.to_account_info();
+        let mut winner_accounts = ctx.remaining_accounts.iter();
+        for (winner, &amount) in winners.iter().zip(amounts.iter()) {
+            if amount == 0 {
+                continue;
+            }
+            let account = winner_accounts.next().ok_or(StealError::InvalidSeasonWinner)?;
+            require!(
+                account.key() == *winner && account.is_writable,
+                StealError::InvalidSeasonWinner
+            );
+            season_info.sub_lamports(amount)?;
+            account.add_lamports(amount)?;
+            msg!("Season {} prize: {} lamports to {}", season.number, amount, winner);
+        }
+        
+        // The remainder and the rent go to the authority as the Season account closes
+        msg!("Season {} closed! Rounds {}-{}, prize pool {} lamports ({} returned to authority)", 
+             season.number, season.first_round, game.round, season.prize_pool, remainder);
+        
+        Ok(())
+    }
+
+    /// Create a game's round history (config authority only, authority funds the rent)
+    /// From then on end_round and reset_round must pass it and append each round, so
+    /// only the authority may opt a game in and change what cranks have to supply
+    pub fn initialize_round_history(ctx: Context<InitializeRoundHistory>) -> Result<()> {
+        let game = &mut ctx.accounts.game;
+        
+        let mut history = ctx.accounts.round_history.load_init()?;
+        history.game = game.key();
+        history.total_recorded = 0;
+        history.bump = ctx.bumps.round_history;
+        
+        game.has_round_history = true;
+        
+        msg!("Round history created for game {} ({} rounds kept)", game.game_id, ROUND_HISTORY_SIZE);
+        
+        Ok(())
+    }
+
+    /// Upgrade the game account to the current layout (permissionless)
+    /// Grows the account in place (payer tops up rent), fills the fields added since
+    /// its layout_version and stamps GAME_LAYOUT_VERSION
+    pub fn migrate_game(ctx: Context<MigrateGame>) -> Result<()> {
+        let game_info = ctx.accounts.game.to_account_info();
+        
+        // Verify account is owned by this program
+        require!(
+            game_info.owner == ctx.program_id,
+            StealError::InvalidAccount
+        );
+        
+        let mut game = {
+            let data = game_info.data.borrow();
+            GameAccount::load_raw(&data)
+                .map_err(|_| StealError::InvalidAccount)?
+        };
+        let from_version = game.layout_version;
+        require!(from_version < GAME_LAYOUT_VERSION, StealError::AlreadyMigrated);
+        
+        // Verify this is a game PDA (layouts before game_id read as game 0)
+        let expected_game_pda = Pubkey::create_program_address(
+            &[b"game", &game.id_seed(), &[game.bump]],
+            ctx.program_id,
+        ).map_err(|_| StealError::InvalidAccount)?;
+        require!(
+            game_info.key() == expected_game_pda,
+            StealError::InvalidAccount
+        );
+        
+        // Top up rent for the larger account
+        let rent_needed = Rent::get()?.minimum_balance(GameAccount::SIZE);
+        let shortfall = rent_needed.saturating_sub(game_info.lamports());
+        if shortfall > 0 {
+            let ix = anchor_lang::solana_program::system_instruction::transfer(
+                &ctx.accounts.payer.key(),
+                &game_info.key(),
+                shortfall,
             );
-            anchor_lang::solana_program::program::invoke_signed(
-                &transfer_ix,
+            anchor_lang::solana_program::program::invoke(
+                &ix,
                 &[
-                    ctx.accounts.game_vault.to_account_info(),
-                    ctx.accounts.old_king.to_account_info(),
+                    ctx.accounts.payer.to_account_info(),
+                    game_info.clone(),
                     ctx.accounts.system_program.to_account_info(),
                 ],
-                &[vault_seeds],
             )?;
+        }
+        
+        if game_info.data_len() < GameAccount::SIZE {
+            game_info.resize(GameAccount::SIZE)?;
+        }
+        
+        // Fill the fields added since from_version and write the account back
+        game.upgrade_layout();
+        if from_version < 10 {
+            // The round in progress was being played with the active ruleset
+            game.rules = ctx.accounts.params.active;
+            game.rules_version = ctx.accounts.params.version;
+        }
+        let mut data = game_info.try_borrow_mut_data()?;
+        game.try_serialize(&mut &mut data[..])?;
+        
+        msg!("Game migrated from layout v{} to v{} ({} bytes)", 
+             from_version, GAME_LAYOUT_VERSION, GameAccount::SIZE);
+        
+        Ok(())
+    }
+
+    /// Steal the crown (main game action)
+    /// Fails instead of charging more than `max_cost`, or if the round, mode
+    /// (true = Hit A Lick) or king (opt-in strict mode) differ from what the caller expected
+    pub fn steal(
+        ctx: Context<Steal>,
+        max_cost: u64,
+        expected_round: Option<u64>,
+        expected_mode: Option<bool>,
+        expected_king: Option<Pubkey>,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        // Circuit breaker: no new steals while paused
+        require!(
+            !ctx.accounts.circuit_breaker.steals_paused,
+            StealError::StealsPaused
+        );
+        // Game clock is frozen while any pause flag is set
+        let now = ctx.accounts.circuit_breaker.game_clock(clock.unix_timestamp);
+        let player = ctx.accounts.player.key();
+        let vault_bump = ctx.bumps.game_vault;
+        let game_seed = ctx.accounts.game.id_seed();
+        let vault_seeds: &[&[u8]] = &[b"vault", &game_seed, &[vault_bump]];
+        
+        let game = &ctx.accounts.game;
+        // The table's own snapshot - another table's round boundary can't change it mid-round
+        let rules = game.rules;
+        
+        // Season of a new round: the current one while its window is open (wall-clock time -
+        // a pause doesn't stretch a season), none before it starts or once it has closed
+        let new_round_season = if game.season_number > 0 && game.total_steals == 0 {
+            let season = ctx.accounts.season.as_ref().ok_or(StealError::InvalidSeason)?;
+            let window = if season.data_is_empty() {
+                None
+            } else {
+                let season = Season::try_deserialize(&mut &season.try_borrow_data()?[..])?;
+                (!season.closed).then_some((season.start_ts, season.end_ts))
+            };
+            round_season_at(clock.unix_timestamp, game.season_number, window)
+        } else {
+            0
+        };
+        
+        // Validate and deserialize config account if provided (manual validation for backward compatibility)
+        // When config is None (null), we skip validation and use game account values
+        let config_data: Option<GameConfig> = if let Some(ref config_acc) = ctx.accounts.config {
+            // Derive expected config PDA
+            let (expected_config_pda, _bump) = Pubkey::find_program_address(
+                &[b"steal-config"],
+                ctx.program_id,
+            );
+            
+            // Verify the provided account matches the expected PDA
+            require!(
+                config_acc.key() == expected_config_pda,
+                StealError::InvalidAccount
+            );
+            
+            // Verify account is owned by this program
+            require!(
+                config_acc.owner == ctx.program_id,
+                StealError::InvalidAccount
+            );
+            
+            // Deserialize the config account (try_deserialize expects full data with discriminator)
+            let data = config_acc.data.borrow();
+            let config = GameConfig::try_deserialize(&mut &data[..])
+                .map_err(|_| StealError::InvalidAccount)?;
+            
+            Some(config)
+        } else {
+            None
+        };
+        
+        // Get expected addresses from config if available, otherwise from game (backward compatibility)
+        // Do this before borrowing game as mutable
+        let (expected_dev_wallet, expected_beast_wallet, _steal_mint) = if let Some(config) = &config_data {
+            (config.dev_wallet, config.beast_wallet, config.steal_mint)
+        } else {
+            (ctx.accounts.game.dev_wallet, ctx.accounts.game.beast_wallet, ctx.accounts.game.steal_mint)
+        };
+        
+        // Entry currency: None for SOL games, validated token accounts for token games
+        let currency = TokenCurrency::resolve(
+            &ctx.accounts.game,
+            &ctx.accounts.game_vault.key(),
+            ctx.accounts.currency_mint.as_deref(),
+            ctx.accounts.token_vault.as_deref(),
+            ctx.accounts.currency_token_program.as_ref(),
+        )?;
+        
+        let game = &mut ctx.accounts.game;
+        
+        // Verify provided accounts match expected addresses
+        require!(
+            ctx.accounts.dev_wallet.key() == expected_dev_wallet,
+            StealError::InvalidAccount
+        );
+        require!(
+            ctx.accounts.beast_wallet.key() == expected_beast_wallet,
+            StealError::InvalidAccount
+        );
+        
+        // Round must still accept steals (timer, Hit A Lick hold rule, cooldown)
+        check_steal_open(&game.steal_snapshot(), player, now, &rules)?;
+        
+        // Calculate entry cost based on token holdings
+        let token_balance = vip_balance(
+            ctx.accounts.player_token_account.as_ref(),
+            &game.steal_mint,
+            &player,
+        )?;
+        let is_vip = is_vip(token_balance, &rules);
+        
+        // Price and split the steal BEFORE any state updates (same plan quote_steal returns)
+        let plan = plan_steal(&game.steal_snapshot(), game.round, player, is_vip, now, &rules)?;
+        let entry_cost = plan.entry_cost;
+        
+        // Slippage: never charge more than the player signed for
+        check_steal_slippage(
+            entry_cost,
+            max_cost,
+            game.round,
+            expected_round,
+            game.is_hit_a_lick_mode,
+            expected_mode,
+        )?;
+        // Strict mode: only steal from the king the caller meant to
+        check_expected_king(game.current_king, expected_king)?;
+        
+        // Verify player has enough of the entry currency
+        let player_balance = party_balance(
+            &ctx.accounts.player.to_account_info(),
+            ctx.accounts.player_currency_account.as_deref(),
+            currency.as_ref(),
+        )?;
+        require!(
+            player_balance >= entry_cost,
+            StealError::InsufficientFunds
+        );
+        
+        // Store old_price before any updates (needed for the event)
+        let old_price = game.current_price;
+        
+        // The dethroned king is always the recorded one - their payout is credited to
+        // their Claim account, so a stale `old_king` passed by the client can't lose it
+        let dethroned_king = game.current_king;
+        let dethroned_entry_price = game.king_entry_price;
+        let yield_payout = plan.old_king_yield;
+        let old_king_payout = plan.old_king_refund.saturating_add(yield_payout);
+        let old_king_reign = plan.old_king_reign;
+        let overhead_dev_cut = plan.dev_cut;
+        let overhead_beast_cut = plan.beast_cut;
+        
+        // Verify the vault can cover everything owed after this steal BEFORE any transfer
+        let total_required_payouts = old_king_payout
+            .saturating_add(overhead_dev_cut)
+            .saturating_add(overhead_beast_cut);
+        
+        let vault_info = ctx.accounts.game_vault.to_account_info();
+        let system_program_info = ctx.accounts.system_program.to_account_info();
+        
+        // Collect payment from player FIRST (safer transaction order)
+        collect_into_vault(
+            &ctx.accounts.player.to_account_info(),
+            ctx.accounts.player_currency_account.as_deref(),
+            &vault_info,
+            currency.as_ref(),
+            &system_program_info,
+            entry_cost,
+        )?;
+        
+        // Check vault balance AFTER collecting entry but BEFORE paying out
+        let vault_balance_after_entry = vault_balance(&vault_info, currency.as_ref())?;
+        require!(
+            vault_balance_after_entry >= total_required_payouts,
+            StealError::InsufficientVaultBalance
+        );
+        
+        // Credit the old king - the funds stay in the vault until they `claim`
+        if old_king_payout > 0 {
+            let claim = ctx.accounts.old_king_claim.as_deref_mut().ok_or(StealError::MissingClaimAccount)?;
+            if claim.wallet == Pubkey::default() {
+                // Created by this steal
+                claim.game = game.key();
+                claim.wallet = dethroned_king;
+                claim.bump = ctx.bumps.old_king_claim.unwrap_or_default();
+            }
+            claim.amount = claim.amount.saturating_add(old_king_payout);
+            claim.total_credited = claim.total_credited.saturating_add(old_king_payout);
+            msg!("Old king {} credited {} lamports (claimable: {})", dethroned_king, old_king_payout, claim.amount);
             
-            // Deduct yield from pool (only if yield was paid)
-            if yield_payout > 0 {
-                game.yield_pool = game.yield_pool.saturating_sub(yield_payout);
+            if ctx.accounts.old_king.key() != dethroned_king {
+                emit_cpi!(OldKingPayoutSkipped {
+                    game: game.key(),
+                    passed_old_king: ctx.accounts.old_king.key(),
+                    current_king: dethroned_king,
+                    credited: old_king_payout,
+                    timestamp: clock.unix_timestamp,
+                });
             }
         }
         
         // Pay dev/beast overhead cuts (using pre-calculated values)
-        // Check vault balance again after paying old king
         if overhead_dev_cut > 0 || overhead_beast_cut > 0 {
-            let vault_balance_after_old_king = ctx.accounts.game_vault.lamports();
+            let vault_balance_before_cuts = vault_balance(&vault_info, currency.as_ref())?;
             require!(
-                vault_balance_after_old_king >= overhead_dev_cut.saturating_add(overhead_beast_cut),
+                vault_balance_before_cuts >= overhead_dev_cut.saturating_add(overhead_beast_cut),
                 StealError::InsufficientVaultBalance
             );
             
-            // Transfer dev cut using CPI
-            if overhead_dev_cut > 0 {
-                let dev_transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
-                    &ctx.accounts.game_vault.key(),
-                    &ctx.accounts.dev_wallet.key(),
-                    overhead_dev_cut,
-                );
-                anchor_lang::solana_program::program::invoke_signed(
-                    &dev_transfer_ix,
-                    &[
-                        ctx.accounts.game_vault.to_account_info(),
-                        ctx.accounts.dev_wallet.to_account_info(),
-                        ctx.accounts.system_program.to_account_info(),
-                    ],
-                    &[vault_seeds],
-                )?;
-            }
+            // Transfer dev cut
+            pay_from_vault(
+                &vault_info,
+                vault_seeds,
+                &ctx.accounts.dev_wallet.to_account_info(),
+                ctx.accounts.dev_currency_account.as_deref(),
+                currency.as_ref(),
+                &system_program_info,
+                overhead_dev_cut,
+            )?;
             
-            // Transfer beast cut using CPI
+            // Transfer beast cut
             if overhead_beast_cut > 0 {
-                let beast_transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
-                    &ctx.accounts.game_vault.key(),
-                    &ctx.accounts.beast_wallet.key(),
+                pay_from_vault(
+                    &vault_info,
+                    vault_seeds,
+                    &ctx.accounts.beast_wallet.to_account_info(),
+                    ctx.accounts.beast_currency_account.as_deref(),
+                    currency.as_ref(),
+                    &system_program_info,
                     overhead_beast_cut,
-                );
-                anchor_lang::solana_program::program::invoke_signed(
-                    &beast_transfer_ix,
-                    &[
-                        ctx.accounts.game_vault.to_account_info(),
-                        ctx.accounts.beast_wallet.to_account_info(),
-                        ctx.accounts.system_program.to_account_info(),
-                    ],
-                    &[vault_seeds],
                 )?;
                 
-                // Track pending SOL for buyback-burn
-                game.beast_sol_pending = game.beast_sol_pending.saturating_add(overhead_beast_cut);
+                // Track pending SOL for buyback-burn (token games pay the beast in their mint)
+                if !game.is_token_game() {
+                    game.beast_sol_pending = game.beast_sol_pending.saturating_add(overhead_beast_cut);
+                }
             }
         }
         
+        // Book the plan: old king's claim, pool adds, dust and any refund drawn from the pot
+        let mut ledger = game.ledger();
+        book_steal(&mut ledger, &plan)?;
+        game.set_ledger(&ledger);
+        
         // Update game state based on mode
+        game.current_price = plan.next_price;
+        game.peak_price = game.peak_price.max(entry_cost);
         if game.is_hit_a_lick_mode {
             // Hit A Lick mode: timer RESETS to 30 seconds on each steal, price stays frozen at hit_a_lick_price
-            game.hit_a_lick_end_time = clock.unix_timestamp + HIT_A_LICK_TIMER as i64;
-            game.current_price = game.hit_a_lick_price;
-            
-            // Update pools with pre-calculated values
-            game.yield_pool = game.yield_pool.saturating_add(overhead_yield_add);
-            game.pending_jackpot = game.pending_jackpot.saturating_add(overhead_jackpot_add);
+            game.hit_a_lick_end_time = now + rules.hit_a_lick_timer as i64;
         } else {
-            // Run It Up mode: price increases 12% (use checked arithmetic)
-            game.current_price = game.current_price
-                .checked_mul(RUN_IT_UP_RATE)
-                .and_then(|v| v.checked_div(100))
-                .unwrap_or(u64::MAX);
-            
+            // Run It Up mode: price increased 12%
             // Add time to existing timer (cap at 10 minutes from now)
-            let new_end = game.round_end_time + TIMER_ADD as i64;
-            let max_end = clock.unix_timestamp + MAX_TIMER as i64;
+            let new_end = game.round_end_time + rules.timer_add as i64;
+            let max_end = now + rules.max_timer as i64;
             game.round_end_time = new_end.min(max_end);
             
-            // Update pools with pre-calculated values
-            game.yield_pool = game.yield_pool.saturating_add(overhead_yield_add);
-            game.pending_jackpot = game.pending_jackpot.saturating_add(overhead_jackpot_add);
-            
             // Increment growth steals counter
             game.growth_steals = game.growth_steals.saturating_add(1);
             
-            // Check for hit a lick mode transition: price >= 60% of jackpot OR steals >= min
-            // Check using NEW price and NEW jackpot (after this steal's contribution)
-            let steals_trigger = game.growth_steals >= game.min_growth_steals_for_war;
-            
-            // Price triggers when NEW price >= 60% of NEW jackpot
-            let price_triggers = if game.jackpot_balance > 0 {
-                let hit_a_lick_threshold = game.jackpot_balance
-                    .checked_mul(HIT_A_LICK_THRESHOLD)
-                    .and_then(|v| v.checked_div(100))
-                    .unwrap_or(u64::MAX);
-                
-                // Trigger when current price >= 60% of jackpot (no minimum threshold check)
-                let triggers = game.current_price >= hit_a_lick_threshold;
-                
-                // Debug logging to help diagnose
+            // Debug logging to help diagnose
+            if game.jackpot_balance > 0 {
                 msg!("Hit A Lick check: price={} lamports, threshold={} lamports (60% of {}), growth_steals={}/{}, triggers={}", 
-                     game.current_price, hit_a_lick_threshold, game.jackpot_balance, game.growth_steals, game.min_growth_steals_for_war, triggers);
-                
-                triggers
-            } else {
-                false
-            };
+                     game.current_price, hit_a_lick_threshold(game.jackpot_balance, &rules), game.jackpot_balance,
+                     game.growth_steals, game.min_growth_steals_for_war, plan.price_trigger);
+            }
             
             // Hit A Lick mode triggers when EITHER price threshold (60% of jackpot) OR minimum steals are met
             // This allows flame bar (price progress) to trigger hit a lick mode when full
-            if price_triggers || steals_trigger {
+            if plan.triggers_hit_a_lick {
                 game.is_hit_a_lick_mode = true;
-                // Calculate hit a lick price: 3% of pot, clamped [0.05, 1.50] SOL
-                game.hit_a_lick_price = calculate_hit_a_lick_price(game.jackpot_balance);
+                game.war_trigger = war_trigger_flags(plan.price_trigger, plan.steals_trigger);
+                game.war_start_ts = now;
+                game.hit_a_lick_price = plan.hit_a_lick_price;
                 // Freeze current_price at hit_a_lick_price (for display/consistency)
                 game.current_price = game.hit_a_lick_price;
-                game.hit_a_lick_end_time = clock.unix_timestamp + HIT_A_LICK_TIMER as i64;
+                game.hit_a_lick_end_time = now + rules.hit_a_lick_timer as i64;
                 // Reset recent kings for new War
                 game.recent_kings = [Pubkey::default(); 3];
                 game.recent_kings_count = 0;
                 msg!("HIT A LICK MODE ACTIVATED! Hit A Lick price: {} lamports (trigger: price={}, steals={})", 
-                     game.hit_a_lick_price, price_triggers, steals_trigger);
+                     game.hit_a_lick_price, plan.price_trigger, plan.steals_trigger);
+                emit_cpi!(HitALickTriggered::from_quote(
+                    game.key(),
+                    &plan,
+                    game.jackpot_balance,
+                    game.growth_steals,
+                    clock.unix_timestamp,
+                ));
             }
         }
         
         // Update king
         game.current_king = player;
-        game.king_since = clock.unix_timestamp;
+        game.king_since = now;
         // CRITICAL: Always set king_entry_price to the actual entry_cost the player paid
         // This preserves the Run It Up price even if this steal triggered HAL transition
         // The entry_cost was calculated BEFORE any state updates, so it correctly captures
@@ -589,11 +1239,80 @@ pub mod steal {
         };
         game.king_was_vip = is_vip;  // Track VIP status for yield calculation
         game.has_king = true;
+        // The round belongs to the season of its first steal
+        if game.total_steals == 0 {
+            game.round_season = new_round_season;
+        }
         game.total_steals += 1;
         
+        // Round telemetry
+        game.round_volume = game.round_volume.saturating_add(entry_cost);
+        if plan.hit_a_lick_mode {
+            game.war_steals = game.war_steals.saturating_add(1);
+        }
+        if plan.old_king != Pubkey::default() {
+            game.note_reign(dethroned_king, old_king_reign);
+        }
+        if note_player(&mut game.player_filter, &player) {
+            game.unique_players = game.unique_players.saturating_add(1);
+        }
+        
+        // Season leaderboard: the stealer's entry counts once their own reign resolves,
+        // the old king's reign resolves now - net of the entry they paid for it
+        if game.round_season > 0 {
+            let loader = ctx.accounts.leaderboard.as_ref().ok_or(StealError::InvalidLeaderboard)?;
+            let mut board = loader.load_mut()?;
+            require!(
+                board.game == game.key() && board.season == game.round_season,
+                StealError::InvalidLeaderboard
+            );
+            update_leaderboard(&mut board.steals, player, BoardUpdate::Add(1));
+            if plan.old_king != Pubkey::default() {
+                let old_king = dethroned_king;
+                update_leaderboard(
+                    &mut board.net_winnings,
+                    old_king,
+                    BoardUpdate::Add(reign_net_winnings(old_king_payout, dethroned_entry_price)),
+                );
+                update_leaderboard(
+                    &mut board.longest_reign,
+                    old_king,
+                    BoardUpdate::Max(i64::try_from(old_king_reign).unwrap_or(i64::MAX)),
+                );
+            }
+            board.updated_at = clock.unix_timestamp;
+        }
+        
+        // Lifetime stats (optional accounts - lamport totals for SOL games only)
+        let sol_game = !game.is_token_game();
+        if let Some(stats) = ctx.accounts.player_stats.as_deref_mut() {
+            let last = if stats.wallet == Pubkey::default() {
+                // Created by this steal
+                stats.wallet = player;
+                stats.bump = ctx.bumps.player_stats.unwrap_or_default();
+                None
+            } else {
+                Some((stats.last_game, stats.last_round))
+            };
+            stats.current_streak = advance_streak(stats.current_streak, last, game.key(), game.round);
+            stats.last_game = game.key();
+            stats.last_round = game.round;
+            stats.total_steals = stats.total_steals.saturating_add(1);
+            if sol_game {
+                stats.lamports_spent = stats.lamports_spent.saturating_add(entry_cost);
+            }
+        }
+        if let Some(stats) = ctx.accounts.old_king_stats.as_deref_mut() {
+            if sol_game && old_king_payout > 0 {
+                stats.refunds_received = stats.refunds_received
+                    .saturating_add(old_king_payout.saturating_sub(yield_payout));
+                stats.yield_received = stats.yield_received.saturating_add(yield_payout);
+            }
+        }
+        
         // Update rate limiting tracking
         game.last_steal_wallet = player;
-        game.last_steal_time = clock.unix_timestamp;
+        game.last_steal_time = now;
         
         // Track recent kings for War mode (3-winner payout)
         if game.is_hit_a_lick_mode {
@@ -605,15 +1324,60 @@ pub mod steal {
         }
         
         msg!("STEAL! {} stole the crown for {} lamports", player, entry_cost);
+        emit_cpi!(CrownStolen::from_quote(
+            game.key(),
+            player,
+            &plan,
+            old_price,
+            game.current_price,
+            game.jackpot_balance,
+            clock.unix_timestamp,
+        ));
+        
+        Ok(())
+    }
+
+    /// Quote a steal by `player` without executing it (simulate only)
+    /// Runs the same checks and plan as `steal` and returns a StealQuote via set_return_data
+    pub fn quote_steal(ctx: Context<QuoteSteal>, player: Pubkey) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(
+            !ctx.accounts.circuit_breaker.steals_paused,
+            StealError::StealsPaused
+        );
+        let now = ctx.accounts.circuit_breaker.game_clock(clock.unix_timestamp);
+        // Read-only: quotes an unmigrated game as it will play after migrate_game
+        let game = &load_game_versioned(&ctx.accounts.game, ctx.program_id)?;
+        // Unmigrated games have no snapshot yet - migrate_game will take the active ruleset
+        let rules = if game.rules_version > 0 { game.rules } else { ctx.accounts.params.active };
+        
+        let snapshot = game.steal_snapshot();
+        check_steal_open(&snapshot, player, now, &rules)?;
+        let token_balance = vip_balance(
+            ctx.accounts.player_token_account.as_ref(),
+            &game.steal_mint,
+            &player,
+        )?;
+        let quote: StealQuote = plan_steal(&snapshot, game.round, player, is_vip(token_balance, &rules), now, &rules)?;
+        
+        anchor_lang::solana_program::program::set_return_data(&quote.try_to_vec()?);
+        
+        msg!("Quote: {} pays {} lamports (hit_a_lick_mode: {}, triggers: {})", 
+             player, quote.entry_cost, quote.hit_a_lick_mode, quote.triggers_hit_a_lick);
         
         Ok(())
     }
 
-    /// End the round and pay the winners (up to 3 in War mode)
+    /// End the round and record the winners' payouts (up to 3 in War mode) for claim_prize
     pub fn end_round(ctx: Context<EndRound>) -> Result<()> {
         let clock = Clock::get()?;
-        let vault_bump = ctx.bumps.game_vault;
-        let vault_seeds: &[&[u8]] = &[b"vault", &[vault_bump]];
+        // Circuit breaker: no settlements while paused
+        require!(
+            !ctx.accounts.circuit_breaker.settlements_paused,
+            StealError::SettlementsPaused
+        );
+        // Game clock is frozen while any pause flag is set
+        let now = ctx.accounts.circuit_breaker.game_clock(clock.unix_timestamp);
         
         // Validate and deserialize config account if provided (manual validation for backward compatibility)
         // When config is None (null), we skip validation and use game account values
@@ -654,6 +1418,7 @@ pub mod steal {
             (ctx.accounts.game.dev_wallet, ctx.accounts.game.beast_wallet, ctx.accounts.game.steal_mint)
         };
         
+        let rules = ctx.accounts.game.rules;
         let game = &mut ctx.accounts.game;
         
         // Verify provided accounts match expected addresses
@@ -669,204 +1434,185 @@ pub mod steal {
         // Check if round has actually ended (hit a lick timer for hit a lick mode)
         if game.is_hit_a_lick_mode {
             require!(
-                clock.unix_timestamp >= game.hit_a_lick_end_time,
+                now >= game.hit_a_lick_end_time,
                 StealError::RoundNotEnded
             );
             
             // Hit A Lick mode: check if king has held for minimum 3 seconds
             require!(game.has_king, StealError::NoKing);
-            let held_secs = (clock.unix_timestamp - game.king_since) as u64;
-            if held_secs < MIN_HIT_A_LICK_HOLD {
+            let held_secs = (now - game.king_since) as u64;
+            if held_secs < rules.min_hit_a_lick_hold {
                 // King hasn't held long enough - extend timer
-                game.hit_a_lick_end_time = game.king_since + MIN_HIT_A_LICK_HOLD as i64;
+                game.hit_a_lick_end_time = game.king_since + rules.min_hit_a_lick_hold as i64;
                 return Err(StealError::KingMustHoldLonger.into());
             }
         } else {
             require!(
-                clock.unix_timestamp >= game.round_end_time,
+                now >= game.round_end_time,
                 StealError::RoundNotEnded
             );
         }
         
-        // Must have a king to end
-        require!(game.has_king, StealError::NoKing);
-        
-        // Calculate payouts based on whether hit a lick mode was reached
-        let total_pot = game.jackpot_balance;
+        // Settle the pot: podium (or dead-round refund + yield) becomes claimable, the rest
+        // plus this round's pending jackpot becomes next round's jackpot
+        let mut ledger = game.ledger();
+        let settlement = settle_round(&game.steal_snapshot(), game.recent_kings_count, now, &rules, &mut ledger)?;
+        game.set_ledger(&ledger);
+        let [winner1_payout, winner2_payout, winner3_payout] = settlement.payouts;
+        let total_pot = settlement.total_pot;
+        let next_jackpot = settlement.rollover;
+        if settlement.mega {
+            msg!("MEGA HIT A LICK! Pot: {} lamports, {} winners!", total_pot, game.recent_kings_count);
+        }
         
-        // For Hit A Lick mode, we pay up to 3 winners from recent_kings
-        // For dead rounds, only the current king gets refund + yield
-        let (winner1_payout, winner2_payout, winner3_payout, dev_payout, beast_payout, next_jackpot) = if game.is_hit_a_lick_mode {
-            // HIT A LICK MODE: Use 3-winner A+ ruleset payouts
-            let is_mega = total_pot >= MEGA_POT_THRESHOLD;
-            
-            // Use calculate_hit_a_lick_end_payouts which returns 6 values now
-            let (w1, w2, w3, dev, beast, rollover) = calculate_hit_a_lick_end_payouts(total_pot);
-            
-            // Only pay winners that exist (skip empty slots)
-            // Special case: if no recent kings, current king gets refund only (NO YIELD in hit a lick mode)
-            let actual_w1 = if game.recent_kings_count >= 1 { 
-                w1 
-            } else { 
-                // No recent kings: current king gets refund only (what they paid), NO YIELD
-                game.king_entry_price
-            };
-            let actual_w2 = if game.recent_kings_count >= 2 { w2 } else { 0 };
-            let actual_w3 = if game.recent_kings_count >= 3 { w3 } else { 0 };
-            
-            // Unclaimed winner shares go back to rollover
-            // If recent_kings_count == 0, we're giving refund to current king, so no unclaimed
-            let unclaimed = if game.recent_kings_count == 0 {
-                // Current king gets refund, rest goes to rollover
-                w1.saturating_add(w2).saturating_add(w3)
-            } else {
-                (if game.recent_kings_count < 1 { w1 } else { 0 })
-                    .saturating_add(if game.recent_kings_count < 2 { w2 } else { 0 })
-                    .saturating_add(if game.recent_kings_count < 3 { w3 } else { 0 })
-            };
-            
-            if is_mega {
-                msg!("MEGA HIT A LICK! Pot: {} lamports, {} winners!", total_pot, game.recent_kings_count);
-            }
-            (actual_w1, actual_w2, actual_w3, dev, beast, rollover.saturating_add(unclaimed))
-        } else {
-            // DEAD ROUND (no hit a lick reached): FULL Refund + yield, pot rolls forward
-            let hold_time = (clock.unix_timestamp - game.king_since) as u64;
-            
-            // Refund: FULL entry price (including lazy tax/surcharge)
-            let refund = game.king_entry_price;
-            
-            // Yield earned (using same formula)
-            // Use a nominal "new payment" for yield calc - use current price as approximation
-            // Use tracked VIP status for yield calculation
-            let winner_was_vip = game.king_was_vip;
-            let yield_earned = calculate_yield_with_cap(
-                game.yield_pool,
-                game.king_entry_price,
-                game.current_price,
-                hold_time,
-                winner_was_vip
-            );
-            
-            let winner_payout = refund + yield_earned;
-            
-            // Pools roll forward intact (minus yield paid)
-            game.yield_pool = game.yield_pool.saturating_sub(yield_earned);
-            
-            (winner_payout, 0, 0, 0, 0, game.jackpot_balance)
-        };
+        // Record the podium as claimable entitlements - each winner withdraws their share
+        // with claim_prize, so no share depends on which accounts the crank passed
+        let prize = &mut ctx.accounts.prize;
+        prize.game = game.key();
+        prize.round = game.round;
+        prize.winners = [game.current_king, game.recent_kings[1], game.recent_kings[2]];
+        prize.amounts = settlement.payouts;
+        prize.claimed = [false; 3];
+        prize.rent_payer = ctx.accounts.payer.key();
+        prize.settled_at = clock.unix_timestamp;
+        prize.bump = ctx.bumps.prize;
+        
+        // The final king's reign ends at settlement
+        let final_king = game.current_king;
+        let final_reign = now.saturating_sub(game.king_since).max(0) as u64;
+        game.note_reign(final_king, final_reign);
         
-        // Pay winner 1 (current king / most recent in recent_kings) using CPI
-        if winner1_payout > 0 {
-            // SECURITY: Validate winner can receive SOL (owned by System Program or empty)
-            // This prevents transfer failures if someone registered a program-owned account as king
-            let winner_owner = ctx.accounts.winner.owner;
+        msg!("Round {} ended! Winner1: {} ({} lamports), Winner2: {} lamports, Winner3: {} lamports (hit_a_lick_mode: {})", 
+             game.round, game.current_king, winner1_payout, winner2_payout, winner3_payout, game.is_hit_a_lick_mode);
+        emit_cpi!(RoundEnded {
+            game: game.key(),
+            round: game.round,
+            winners: prize.winners,
+            payouts: prize.amounts,
+            hit_a_lick_mode: game.is_hit_a_lick_mode,
+            mega: settlement.mega,
+            total_pot,
+            next_jackpot: game.jackpot_balance,
+            dust: settlement.dust,
+            longest_reign_secs: game.longest_reign_secs,
+            longest_reign_wallet: game.longest_reign_wallet,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        // Season leaderboard: the final reign resolves net of its entry, the other podium
+        // places settled their entries when dethroned so their prizes count in full
+        if game.round_season > 0 {
+            let loader = ctx.accounts.leaderboard.as_ref().ok_or(StealError::InvalidLeaderboard)?;
+            let mut board = loader.load_mut()?;
             require!(
-                *winner_owner == anchor_lang::solana_program::system_program::ID || 
-                ctx.accounts.winner.lamports() == 0,
-                StealError::InvalidAccount
+                board.game == game.key() && board.season == game.round_season,
+                StealError::InvalidLeaderboard
             );
-            
-            let winner_transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
-                &ctx.accounts.game_vault.key(),
-                &ctx.accounts.winner.key(),
-                winner1_payout,
+            let king = game.current_king;
+            update_leaderboard(
+                &mut board.net_winnings,
+                king,
+                BoardUpdate::Add(reign_net_winnings(winner1_payout, game.king_entry_price)),
             );
-            anchor_lang::solana_program::program::invoke_signed(
-                &winner_transfer_ix,
-                &[
-                    ctx.accounts.game_vault.to_account_info(),
-                    ctx.accounts.winner.to_account_info(),
-                    ctx.accounts.system_program.to_account_info(),
-                ],
-                &[vault_seeds],
-            )?;
-        }
-        
-        // Pay winner 2 (if exists and we have a valid account)
-        if winner2_payout > 0 {
-            if let Some(ref winner2) = ctx.accounts.winner2 {
-                // Verify winner2 matches recent_kings[1]
-                require!(
-                    winner2.key() == game.recent_kings[1],
-                    StealError::InvalidWinner
-                );
-                
-                // SECURITY: Validate winner2 can receive SOL
-                let winner2_owner = winner2.owner;
-                require!(
-                    *winner2_owner == anchor_lang::solana_program::system_program::ID || 
-                    winner2.lamports() == 0,
-                    StealError::InvalidAccount
+            update_leaderboard(&mut board.longest_reign, king, BoardUpdate::Max(now - game.king_since));
+            if game.is_hit_a_lick_mode && game.recent_kings_count >= 1 {
+                update_leaderboard(&mut board.wars_won, king, BoardUpdate::Add(1));
+            }
+            if winner2_payout > 0 {
+                update_leaderboard(
+                    &mut board.net_winnings,
+                    game.recent_kings[1],
+                    BoardUpdate::Add(i64::try_from(winner2_payout).unwrap_or(i64::MAX)),
                 );
-                
-                let w2_transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
-                    &ctx.accounts.game_vault.key(),
-                    &winner2.key(),
-                    winner2_payout,
+            }
+            if winner3_payout > 0 {
+                update_leaderboard(
+                    &mut board.net_winnings,
+                    game.recent_kings[2],
+                    BoardUpdate::Add(i64::try_from(winner3_payout).unwrap_or(i64::MAX)),
                 );
-                anchor_lang::solana_program::program::invoke_signed(
-                    &w2_transfer_ix,
-                    &[
-                        ctx.accounts.game_vault.to_account_info(),
-                        winner2.to_account_info(),
-                        ctx.accounts.system_program.to_account_info(),
-                    ],
-                    &[vault_seeds],
-                )?;
             }
+            board.updated_at = clock.unix_timestamp;
         }
         
-        // Pay winner 3 (if exists and we have a valid account)
+        // Lifetime stats (optional accounts - lamport totals for SOL games only)
+        let sol_game = !game.is_token_game();
+        let war_won = game.is_hit_a_lick_mode && game.recent_kings_count >= 1;
+        if let Some(stats) = ctx.accounts.winner_stats.as_deref_mut() {
+            if war_won {
+                stats.war_wins[0] = stats.war_wins[0].saturating_add(1);
+                if sol_game {
+                    stats.prizes_received = stats.prizes_received.saturating_add(winner1_payout);
+                }
+            } else if sol_game {
+                // Dead round (or a war with no podium): refund plus any yield
+                stats.refunds_received = stats.refunds_received
+                    .saturating_add(winner1_payout.saturating_sub(settlement.yield_paid));
+                stats.yield_received = stats.yield_received.saturating_add(settlement.yield_paid);
+            }
+        }
+        if winner2_payout > 0 {
+            if let Some(stats) = ctx.accounts.winner2_stats.as_deref_mut() {
+                stats.war_wins[1] = stats.war_wins[1].saturating_add(1);
+                if sol_game {
+                    stats.prizes_received = stats.prizes_received.saturating_add(winner2_payout);
+                }
+            }
+        }
         if winner3_payout > 0 {
-            if let Some(ref winner3) = ctx.accounts.winner3 {
-                // Verify winner3 matches recent_kings[2]
-                require!(
-                    winner3.key() == game.recent_kings[2],
-                    StealError::InvalidWinner
-                );
-                
-                // SECURITY: Validate winner3 can receive SOL
-                let winner3_owner = winner3.owner;
-                require!(
-                    *winner3_owner == anchor_lang::solana_program::system_program::ID || 
-                    winner3.lamports() == 0,
-                    StealError::InvalidAccount
-                );
-                
-                let w3_transfer_ix = anchor_lang::solana_program::system_instruction::transfer(
-                    &ctx.accounts.game_vault.key(),
-                    &winner3.key(),
-                    winner3_payout,
-                );
-                anchor_lang::solana_program::program::invoke_signed(
-                    &w3_transfer_ix,
-                    &[
-                        ctx.accounts.game_vault.to_account_info(),
-                        winner3.to_account_info(),
-                        ctx.accounts.system_program.to_account_info(),
-                    ],
-                    &[vault_seeds],
-                )?;
+            if let Some(stats) = ctx.accounts.winner3_stats.as_deref_mut() {
+                stats.war_wins[2] = stats.war_wins[2].saturating_add(1);
+                if sol_game {
+                    stats.prizes_received = stats.prizes_received.saturating_add(winner3_payout);
+                }
             }
         }
         
-        // Dev and beast fees come from entries only, not from pot
-        // dev_payout and beast_payout are always 0 (constants set to 0)
-        
-        msg!("Round {} ended! Winner1: {} ({} lamports), Winner2: {} lamports, Winner3: {} lamports (hit_a_lick_mode: {})", 
-             game.round, game.current_king, winner1_payout, winner2_payout, winner3_payout, game.is_hit_a_lick_mode);
+        // Round history: the finished round's result, while its totals are still on the game
+        if game.has_round_history {
+            let loader = ctx.accounts.round_history.as_ref().ok_or(StealError::InvalidRoundHistory)?;
+            let mut history = loader.load_mut()?;
+            require!(history.game == game.key(), StealError::InvalidRoundHistory);
+            let history = &mut *history;
+            push_round_record(&mut history.records, &mut history.total_recorded, RoundRecord {
+                round: game.round,
+                start_ts: game.round_start_ts,
+                end_ts: clock.unix_timestamp,
+                total_steals: game.total_steals,
+                peak_price: game.peak_price,
+                jackpot_before: total_pot,
+                jackpot_after: game.jackpot_balance,
+                payouts: prize.amounts,
+                winners: prize.winners,
+                war_reached: game.is_hit_a_lick_mode as u8,
+                war_trigger: game.war_trigger,
+                ..RoundRecord::default()
+            });
+        }
         
-        // Set jackpot for next round (rollover amount + pending jackpot from this round)
-        game.jackpot_balance = next_jackpot.saturating_add(game.pending_jackpot);
-        // Reset pending jackpot for next round
-        game.pending_jackpot = 0;
-        // Note: yield_pool unchanged in hit a lick mode, already adjusted in dead round
+        // Round boundary: a staged ruleset takes effect for the next round
+        let params = &mut ctx.accounts.params;
+        if params.apply_staged() {
+            msg!("Params version {} now active", params.version);
+        }
+        let rules = params.active;
+        // This table plays its next round with the ruleset active now
+        if game.rules_version != params.version {
+            emit_cpi!(ParamsChanged {
+                change_id: None,
+                game: Some(game.key()),
+                version: params.version,
+                rules,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        game.rules = rules;
+        game.rules_version = params.version;
         
         // Reset for next round
-        game.current_price = START_PRICE;
+        game.current_price = game.tier.start_price;
         game.hit_a_lick_price = 0;
-        game.round_end_time = clock.unix_timestamp + MAX_TIMER as i64;
+        game.round_end_time = now + rules.max_timer as i64;
         game.hit_a_lick_end_time = 0;
         game.is_hit_a_lick_mode = false;
         game.current_king = Pubkey::default();
@@ -879,11 +1625,17 @@ pub mod steal {
         game.recent_kings_count = 0;
         game.last_steal_wallet = Pubkey::default();
         game.last_steal_time = 0;
+        game.cooldown_seconds = rules.hit_a_lick_cooldown_secs;
         game.growth_steals = 0;
-        game.min_growth_steals_for_war = compute_min_growth_steals_for_war(next_jackpot);
-        game.growth_hard_end_ts = clock.unix_timestamp + GROWTH_MAX_DURATION_SECS;
+        game.min_growth_steals_for_war = compute_min_growth_steals_for_war(next_jackpot, game.currency_decimals);
+        game.growth_hard_end_ts = now + GROWTH_MAX_DURATION_SECS;
         game.round += 1;
         game.total_steals = 0;
+        game.round_season = 0;
+        game.round_start_ts = clock.unix_timestamp;
+        game.peak_price = 0;
+        game.war_trigger = 0;
+        game.clear_round_telemetry();
         // Note: total_burned and beast_sol_pending persist across rounds
         
         Ok(())
@@ -892,12 +1644,19 @@ pub mod steal {
     /// Reset a round that ended with no winner
     /// This allows the game to continue when nobody played before timer ran out
     pub fn reset_round(ctx: Context<ResetRound>) -> Result<()> {
-        let game = &mut ctx.accounts.game;
         let clock = Clock::get()?;
-        
-        // Check if round has actually ended
+        // Circuit breaker: no settlements while paused
         require!(
-            clock.unix_timestamp >= game.round_end_time,
+            !ctx.accounts.circuit_breaker.settlements_paused,
+            StealError::SettlementsPaused
+        );
+        // Game clock is frozen while any pause flag is set
+        let now = ctx.accounts.circuit_breaker.game_clock(clock.unix_timestamp);
+        let game = &mut ctx.accounts.game;
+        
+        // Check if round has actually ended
+        require!(
+            now >= game.round_end_time,
             StealError::RoundNotEnded
         );
         
@@ -907,22 +1666,293 @@ pub mod steal {
         msg!("Round {} reset - no winner this round", game.round);
         
         // Merge pending jackpot into jackpot balance for next round
+        let jackpot_before = game.jackpot_balance;
         game.jackpot_balance = game.jackpot_balance.saturating_add(game.pending_jackpot);
         game.pending_jackpot = 0;
         
+        // Round history: an empty round, no winners
+        if game.has_round_history {
+            let loader = ctx.accounts.round_history.as_ref().ok_or(StealError::InvalidRoundHistory)?;
+            let mut history = loader.load_mut()?;
+            require!(history.game == game.key(), StealError::InvalidRoundHistory);
+            let history = &mut *history;
+            push_round_record(&mut history.records, &mut history.total_recorded, RoundRecord {
+                round: game.round,
+                start_ts: game.round_start_ts,
+                end_ts: clock.unix_timestamp,
+                total_steals: game.total_steals,
+                peak_price: game.peak_price,
+                jackpot_before,
+                jackpot_after: game.jackpot_balance,
+                ..RoundRecord::default()
+            });
+        }
+        emit_cpi!(RoundReset {
+            game: game.key(),
+            round: game.round,
+            jackpot_balance: game.jackpot_balance,
+            longest_reign_secs: game.longest_reign_secs,
+            longest_reign_wallet: game.longest_reign_wallet,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        // Round boundary: a staged ruleset takes effect for the next round
+        let params = &mut ctx.accounts.params;
+        if params.apply_staged() {
+            msg!("Params version {} now active", params.version);
+        }
+        let rules = params.active;
+        // This table plays its next round with the ruleset active now
+        if game.rules_version != params.version {
+            emit_cpi!(ParamsChanged {
+                change_id: None,
+                game: Some(game.key()),
+                version: params.version,
+                rules,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        game.rules = rules;
+        game.rules_version = params.version;
+        
         // Reset for next round (keep pools intact)
-        game.current_price = START_PRICE;
+        game.current_price = game.tier.start_price;
         game.hit_a_lick_price = 0;
-        game.round_end_time = clock.unix_timestamp + MAX_TIMER as i64;
+        game.round_end_time = now + rules.max_timer as i64;
         game.hit_a_lick_end_time = 0;
         game.is_hit_a_lick_mode = false;
+        // A mid-war emergency refund can leave earlier war kings behind
+        game.recent_kings = [Pubkey::default(); 3];
+        game.recent_kings_count = 0;
         game.last_steal_wallet = Pubkey::default();
         game.last_steal_time = 0;
+        game.cooldown_seconds = rules.hit_a_lick_cooldown_secs;
         game.growth_steals = 0;
-        game.min_growth_steals_for_war = compute_min_growth_steals_for_war(game.jackpot_balance);
-        game.growth_hard_end_ts = clock.unix_timestamp + GROWTH_MAX_DURATION_SECS;
+        game.min_growth_steals_for_war = compute_min_growth_steals_for_war(game.jackpot_balance, game.currency_decimals);
+        game.growth_hard_end_ts = now + GROWTH_MAX_DURATION_SECS;
         game.round += 1;
         game.total_steals = 0;
+        game.round_season = 0;
+        game.round_start_ts = clock.unix_timestamp;
+        game.peak_price = 0;
+        game.war_trigger = 0;
+        game.clear_round_telemetry();
+        
+        Ok(())
+    }
+
+    /// Reconcile vault lamports against what the game owes (read-only, permissionless)
+    /// Returns a VaultAudit via set_return_data and emits VaultAudited; fails with
+    /// VaultUndercollateralized if the vault can't cover its liabilities
+    pub fn audit_vault(ctx: Context<AuditVault>) -> Result<()> {
+        let clock = Clock::get()?;
+        // Read-only: audits games of any layout version without migrating them first
+        let game = &load_game_versioned(&ctx.accounts.game, ctx.program_id)?;
+        let (expected_vault, _) = Pubkey::find_program_address(&[b"vault", game.id_seed().as_ref()], ctx.program_id);
+        require!(ctx.accounts.game_vault.key() == expected_vault, StealError::InvalidAccount);
+        let currency = TokenCurrency::resolve(
+            game,
+            &ctx.accounts.game_vault.key(),
+            ctx.accounts.currency_mint.as_deref(),
+            ctx.accounts.token_vault.as_deref(),
+            ctx.accounts.currency_token_program.as_ref(),
+        )?;
+        let vault_lamports = vault_balance(&ctx.accounts.game_vault.to_account_info(), currency.as_ref())?;
+        
+        let liabilities = calculate_vault_liabilities(
+            game.jackpot_balance,
+            game.pending_jackpot,
+            game.yield_pool,
+            game.claims_outstanding,
+            game.vault_reserve(),
+        )?;
+        let (surplus, deficit) = calculate_vault_surplus(vault_lamports, liabilities);
+        
+        let audit = VaultAudit {
+            vault_lamports,
+            liabilities,
+            surplus,
+            deficit,
+        };
+        anchor_lang::solana_program::program::set_return_data(&audit.try_to_vec()?);
+        
+        emit_cpi!(VaultAudited {
+            game: ctx.accounts.game.key(),
+            vault_lamports,
+            liabilities,
+            surplus,
+            deficit,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        msg!("Vault audit: {} lamports, liabilities {}, surplus {}, deficit {}", 
+             vault_lamports, liabilities, surplus, deficit);
+        
+        require!(deficit == 0, StealError::VaultUndercollateralized);
+        
+        Ok(())
+    }
+
+    /// Move vault lamports no ledger field accounts for (rounding remainders, direct
+    /// transfers, stranded shares) into the jackpot or to the dev wallet (authority only)
+    /// `amount = None` sweeps the whole surplus; liabilities are recomputed on-chain so
+    /// nothing owed to players can ever be swept
+    pub fn sweep_surplus(
+        ctx: Context<SweepSurplus>,
+        amount: Option<u64>,
+        destination: SweepDestination,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let vault_bump = ctx.bumps.game_vault;
+        let game_seed = ctx.accounts.game.id_seed();
+        let vault_seeds: &[&[u8]] = &[b"vault", &game_seed, &[vault_bump]];
+        let currency = TokenCurrency::resolve(
+            &ctx.accounts.game,
+            &ctx.accounts.game_vault.key(),
+            ctx.accounts.currency_mint.as_deref(),
+            ctx.accounts.token_vault.as_deref(),
+            ctx.accounts.currency_token_program.as_ref(),
+        )?;
+        let game = &mut ctx.accounts.game;
+        let vault_lamports = vault_balance(&ctx.accounts.game_vault.to_account_info(), currency.as_ref())?;
+        
+        let liabilities = calculate_vault_liabilities(
+            game.jackpot_balance,
+            game.pending_jackpot,
+            game.yield_pool,
+            game.claims_outstanding,
+            game.vault_reserve(),
+        )?;
+        let (surplus_before, _deficit) = calculate_vault_surplus(vault_lamports, liabilities);
+        let amount = calculate_sweep_amount(vault_lamports, liabilities, amount)?;
+        
+        match destination {
+            SweepDestination::Jackpot => {
+                // Lamports are already in the vault - just make them owed to the pot
+                game.jackpot_balance = game.jackpot_balance
+                    .checked_add(amount)
+                    .ok_or(StealError::Overflow)?;
+            }
+            SweepDestination::DevWallet => {
+                pay_from_vault(
+                    &ctx.accounts.game_vault.to_account_info(),
+                    vault_seeds,
+                    &ctx.accounts.dev_wallet.to_account_info(),
+                    ctx.accounts.dev_currency_account.as_deref(),
+                    currency.as_ref(),
+                    &ctx.accounts.system_program.to_account_info(),
+                    amount,
+                )?;
+            }
+        }
+        
+        emit_cpi!(SurplusSwept {
+            game: game.key(),
+            amount,
+            to_jackpot: destination == SweepDestination::Jackpot,
+            surplus_before,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        msg!("Surplus swept: {} of {} lamports to {:?}", amount, surplus_before, destination);
+        
+        Ok(())
+    }
+
+    /// Withdraw a wallet's claimable balance from the game vault
+    /// Not gated by the circuit breaker - credited funds are always withdrawable
+    pub fn claim(ctx: Context<ClaimPayout>) -> Result<()> {
+        let vault_bump = ctx.bumps.game_vault;
+        let game_seed = ctx.accounts.game.id_seed();
+        let vault_seeds: &[&[u8]] = &[b"vault", &game_seed, &[vault_bump]];
+        let currency = TokenCurrency::resolve(
+            &ctx.accounts.game,
+            &ctx.accounts.game_vault.key(),
+            ctx.accounts.currency_mint.as_deref(),
+            ctx.accounts.token_vault.as_deref(),
+            ctx.accounts.currency_token_program.as_ref(),
+        )?;
+        
+        let amount = ctx.accounts.claim.amount;
+        require!(amount > 0, StealError::NothingToClaim);
+        
+        pay_from_vault(
+            &ctx.accounts.game_vault.to_account_info(),
+            vault_seeds,
+            &ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.wallet_currency_account.as_deref(),
+            currency.as_ref(),
+            &ctx.accounts.system_program.to_account_info(),
+            amount,
+        )?;
+        
+        let claim = &mut ctx.accounts.claim;
+        claim.amount = 0;
+        claim.total_claimed = claim.total_claimed.saturating_add(amount);
+        let game = &mut ctx.accounts.game;
+        game.claims_outstanding = game.claims_outstanding.saturating_sub(amount);
+        
+        msg!("Claimed {} lamports for {}", amount, claim.wallet);
+        emit_cpi!(PayoutClaimed {
+            game: game.key(),
+            wallet: claim.wallet,
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Withdraw the caller's podium payouts for a settled round
+    /// The record is closed (rent back to the crank) once every place is claimed
+    pub fn claim_prize(ctx: Context<ClaimPrize>, round: u64) -> Result<()> {
+        let vault_bump = ctx.bumps.game_vault;
+        let game_seed = ctx.accounts.game.id_seed();
+        let vault_seeds: &[&[u8]] = &[b"vault", &game_seed, &[vault_bump]];
+        let currency = TokenCurrency::resolve(
+            &ctx.accounts.game,
+            &ctx.accounts.game_vault.key(),
+            ctx.accounts.currency_mint.as_deref(),
+            ctx.accounts.token_vault.as_deref(),
+            ctx.accounts.currency_token_program.as_ref(),
+        )?;
+        
+        let wallet = ctx.accounts.wallet.key();
+        let prize = &ctx.accounts.prize;
+        let (amount, places) = unclaimed_prize(&prize.winners, &prize.amounts, &prize.claimed, &wallet);
+        require!(amount > 0, StealError::NothingToClaim);
+        
+        pay_from_vault(
+            &ctx.accounts.game_vault.to_account_info(),
+            vault_seeds,
+            &ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.wallet_currency_account.as_deref(),
+            currency.as_ref(),
+            &ctx.accounts.system_program.to_account_info(),
+            amount,
+        )?;
+        
+        let game = &mut ctx.accounts.game;
+        game.claims_outstanding = game.claims_outstanding.saturating_sub(amount);
+        
+        let prize = &mut ctx.accounts.prize;
+        for (done, &won) in prize.claimed.iter_mut().zip(places.iter()) {
+            *done |= won;
+        }
+        
+        msg!("Round {} prize claimed: {} lamports for {}", round, amount, wallet);
+        emit_cpi!(PrizeClaimed {
+            game: ctx.accounts.game.key(),
+            round,
+            wallet,
+            amount,
+            places,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        if prizes_settled(&prize.amounts, &prize.claimed) {
+            prize.close(ctx.accounts.rent_payer.to_account_info())?;
+        }
         
         Ok(())
     }
@@ -939,34 +1969,87 @@ pub mod steal {
         );
         
         // Mock swap rate: 1 SOL = 100,000 $STEAL (for devnet testing)
-        // With 9 decimals: 1 SOL = 100,000 * 10^9 = 100_000_000_000_000 base units
-        const MOCK_RATE: u64 = 100_000_000_000_000; // tokens per SOL (with decimals)
-        let tokens_to_burn = (amount_sol as u128 * MOCK_RATE as u128 / LAMPORTS_PER_SOL as u128) as u64;
+        // In base units of the mint: 100,000 * 10^decimals (100_000_000_000_000 with 9 decimals)
+        const MOCK_RATE_WHOLE_TOKENS: u128 = 100_000;
+        let mock_rate = MOCK_RATE_WHOLE_TOKENS * 10u128.pow(ctx.accounts.steal_mint.decimals as u32);
+        let tokens_to_burn = (amount_sol as u128 * mock_rate / LAMPORTS_PER_SOL as u128) as u64;
         
         // Transfer SOL from beast wallet (for real swap, this would go to DEX)
         // For mock, we just update the tracking
         game.beast_sol_pending -= amount_sol;
         
-        // Burn tokens from burn pool
-        let cpi_accounts = anchor_spl::token::Burn {
+        // Burn tokens from burn pool (Token or Token-2022, whichever owns the mint)
+        let cpi_accounts = token_interface::Burn {
             mint: ctx.accounts.steal_mint.to_account_info(),
             from: ctx.accounts.burn_pool.to_account_info(),
             authority: ctx.accounts.burn_authority.to_account_info(),
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        anchor_spl::token::burn(cpi_ctx, tokens_to_burn)?;
+        token_interface::burn(cpi_ctx, tokens_to_burn)?;
         
         // Update total burned
         game.total_burned += tokens_to_burn;
         
         msg!("BEAST BURN! {} SOL converted to {} $STEAL tokens and burned! Total burned: {}", 
              amount_sol, tokens_to_burn, game.total_burned);
+        emit_cpi!(BeastBurned {
+            game: game.key(),
+            amount_sol,
+            tokens_burned: tokens_to_burn,
+            total_burned: game.total_burned,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
         
         Ok(())
     }
 }
 
+// ============================================================================
+// VERSIONED GAME READS
+// ============================================================================
+
+/// Load a game account written with any layout version, upgraded in memory
+/// For read-only instructions: checks the owner and the game PDA, never writes back
+#[cfg(any(not(test), feature = "idl-build"))]
+fn load_game_versioned(game_info: &AccountInfo, program_id: &Pubkey) -> Result<GameAccount> {
+    require!(game_info.owner == program_id, StealError::InvalidAccount);
+    let game = {
+        let data = game_info.try_borrow_data()?;
+        GameAccount::load_versioned(&data).map_err(|_| StealError::InvalidAccount)?
+    };
+    let expected_game = Pubkey::create_program_address(
+        &[b"game", game.id_seed().as_ref(), &[game.bump]],
+        program_id,
+    ).map_err(|_| StealError::InvalidAccount)?;
+    require!(game_info.key() == expected_game, StealError::InvalidAccount);
+    Ok(game)
+}
+
+// ============================================================================
+// VIP CHECK
+// ============================================================================
+
+/// $STEAL balance that counts toward VIP for `player` (0 without a token account)
+/// Accepts Token and Token-2022 accounts; frozen accounts count as 0
+#[cfg(any(not(test), feature = "idl-build"))]
+fn vip_balance(
+    token_acc: Option<&UncheckedAccount>,
+    steal_mint: &Pubkey,
+    player: &Pubkey,
+) -> Result<u64> {
+    let Some(token_acc) = token_acc else {
+        return Ok(0);
+    };
+    
+    // Token or Token-2022 account (extensions parsed) for the $STEAL mint, owned by the player
+    let balance = vip_account_balance(token_acc.owner, &token_acc.data.borrow(), steal_mint, player)?;
+    if balance == 0 {
+        msg!("VIP check: token account {} counts no balance (empty or frozen)", token_acc.key());
+    }
+    Ok(balance)
+}
+
 // ============================================================================
 // ACCOUNT CONTEXTS
 // ============================================================================
@@ -1000,149 +2083,1015 @@ pub struct InitializeConfig<'info> {
 
 #[cfg(any(not(test), feature = "idl-build"))]
 #[derive(Accounts)]
-pub struct InitializeGame<'info> {
+#[instruction(change_id: u64)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        seeds = [b"steal-config"],
+        bump = config.bump,
+        has_one = authority @ StealError::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
+    
     #[account(
         init,
         payer = authority,
-        space = GameAccount::SIZE,
-        seeds = [b"game"],
+        space = PendingChange::SIZE,
+        seeds = [b"pending-change", change_id.to_le_bytes().as_ref()],
         bump
     )]
-    pub game: Account<'info, GameAccount>,
+    pub pending_change: Account<'info, PendingChange>,
     
     #[account(mut)]
     pub authority: Signer<'info>,
     
-    /// CHECK: Dev wallet address
+    /// CHECK: New dev wallet address
     pub dev_wallet: UncheckedAccount<'info>,
     
-    /// CHECK: Beast wallet address
+    /// CHECK: New beast wallet address
     pub beast_wallet: UncheckedAccount<'info>,
     
-    /// CHECK: STEAL token mint
+    /// CHECK: New STEAL token mint
     pub steal_mint: UncheckedAccount<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
 #[cfg(any(not(test), feature = "idl-build"))]
+#[event_cpi]
 #[derive(Accounts)]
-pub struct Steal<'info> {
+pub struct ProposeAuthority<'info> {
     #[account(
         mut,
-        seeds = [b"game"],
-        bump = game.bump
+        seeds = [b"steal-config"],
+        bump = config.bump,
+        has_one = authority @ StealError::Unauthorized
     )]
-    pub game: Account<'info, GameAccount>,
-    
-    #[account(mut)]
-    pub player: Signer<'info>,
+    pub config: Account<'info, GameConfig>,
     
-    /// CHECK: Player's STEAL token account (optional - for VIP check)
-    /// We use UncheckedAccount to allow non-existent accounts for non-VIP players
-    pub player_token_account: Option<UncheckedAccount<'info>>,
+    pub authority: Signer<'info>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"steal-config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GameConfig>,
     
-    /// Game vault PDA (owned by System Program)
+    /// Must match config.pending_authority
+    pub new_authority: Signer<'info>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: Legacy config PDA - layout validated manually (Anchor can't deserialize it yet)
     #[account(
         mut,
-        seeds = [b"vault"],
+        seeds = [b"steal-config"],
         bump
     )]
-    pub game_vault: SystemAccount<'info>,
+    pub config: UncheckedAccount<'info>,
     
-    /// CHECK: Old king's wallet (for payout)
     #[account(mut)]
-    pub old_king: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
     
-    /// CHECK: Config account PDA (optional - manually validated if provided)
-    /// When provided, must be the correct PDA derived from seeds [b"steal-config"]
-    pub config: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[derive(Accounts)]
+pub struct InitializeParams<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = GameParams::SIZE,
+        seeds = [b"steal-params"],
+        bump
+    )]
+    pub params: Account<'info, GameParams>,
+    
+    #[account(
+        seeds = [b"steal-config"],
+        bump = config.bump,
+        has_one = authority @ StealError::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
     
-    /// CHECK: Dev wallet (must match config.dev_wallet if config exists, else game.dev_wallet)
     #[account(mut)]
-    pub dev_wallet: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[derive(Accounts)]
+#[instruction(change_id: u64)]
+pub struct SetParams<'info> {
+    #[account(
+        seeds = [b"steal-config"],
+        bump = config.bump,
+        has_one = authority @ StealError::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = PendingChange::SIZE,
+        seeds = [b"pending-change", change_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingChange>,
     
-    /// CHECK: Beast wallet (must match config.beast_wallet if config exists, else game.beast_wallet)
     #[account(mut)]
-    pub beast_wallet: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
 }
 
 #[cfg(any(not(test), feature = "idl-build"))]
+#[event_cpi]
 #[derive(Accounts)]
-pub struct EndRound<'info> {
+pub struct ExecuteChange<'info> {
     #[account(
         mut,
-        seeds = [b"game"],
-        bump = game.bump
+        seeds = [b"pending-change", pending_change.id.to_le_bytes().as_ref()],
+        bump = pending_change.bump,
+        has_one = proposer,
+        close = proposer
     )]
-    pub game: Account<'info, GameAccount>,
+    pub pending_change: Account<'info, PendingChange>,
+    
+    /// CHECK: Receives the pending change rent (must match pending_change.proposer)
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
     
-    /// Game vault PDA (owned by System Program)
     #[account(
         mut,
-        seeds = [b"vault"],
-        bump
+        seeds = [b"steal-config"],
+        bump = config.bump
     )]
-    pub game_vault: SystemAccount<'info>,
+    pub config: Account<'info, GameConfig>,
     
-    /// CHECK: Winner's wallet (must be current king / recent_kings[0])
-    #[account(mut, constraint = winner.key() == game.current_king)]
-    pub winner: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"steal-params"],
+        bump = params.bump
+    )]
+    pub params: Account<'info, GameParams>,
     
-    /// CHECK: Config account PDA (optional - manually validated if provided)
-    /// When provided, must be the correct PDA derived from seeds [b"steal-config"]
-    pub config: Option<UncheckedAccount<'info>>,
+    /// Game mirroring the config (required for config changes only - a ruleset change
+    /// is staged on the params account and snapshotted by each game at its next round)
+    #[account(
+        mut,
+        seeds = [b"game", game.id_seed().as_ref()],
+        bump = game.bump,
+        constraint = game.layout_version == GAME_LAYOUT_VERSION @ StealError::GameNotMigrated
+    )]
+    pub game: Option<Account<'info, GameAccount>>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[derive(Accounts)]
+pub struct CancelChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"pending-change", pending_change.id.to_le_bytes().as_ref()],
+        bump = pending_change.bump,
+        close = authority
+    )]
+    pub pending_change: Account<'info, PendingChange>,
     
-    /// CHECK: 2nd place winner (optional - must match recent_kings[1] if provided)
-    #[account(mut)]
-    pub winner2: Option<UncheckedAccount<'info>>,
+    #[account(
+        seeds = [b"steal-config"],
+        bump = config.bump,
+        has_one = authority @ StealError::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
     
-    /// CHECK: 3rd place winner (optional - must match recent_kings[2] if provided)
     #[account(mut)]
-    pub winner3: Option<UncheckedAccount<'info>>,
+    pub authority: Signer<'info>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[derive(Accounts)]
+pub struct InitializeCircuitBreaker<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = CircuitBreaker::SIZE,
+        seeds = [b"circuit-breaker", game.key().as_ref()],
+        bump
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
     
-    /// CHECK: Dev wallet (must match config.dev_wallet if config exists, else game.dev_wallet)
-    #[account(mut)]
-    pub dev_wallet: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"game", game.id_seed().as_ref()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, GameAccount>,
+    
+    #[account(
+        seeds = [b"steal-config"],
+        bump = config.bump,
+        has_one = authority @ StealError::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
     
-    /// CHECK: Beast wallet (must match config.beast_wallet if config exists, else game.beast_wallet)
     #[account(mut)]
-    pub beast_wallet: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
 #[cfg(any(not(test), feature = "idl-build"))]
+#[event_cpi]
 #[derive(Accounts)]
-pub struct ResetRound<'info> {
+pub struct SetPause<'info> {
     #[account(
         mut,
-        seeds = [b"game"],
-        bump = game.bump
+        seeds = [b"circuit-breaker", game.key().as_ref()],
+        bump = circuit_breaker.bump
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+    
+    #[account(
+        mut,
+        seeds = [b"game", game.id_seed().as_ref()],
+        bump = game.bump,
+        constraint = game.layout_version == GAME_LAYOUT_VERSION @ StealError::GameNotMigrated
     )]
     pub game: Account<'info, GameAccount>,
+    
+    #[account(
+        seeds = [b"steal-config"],
+        bump = config.bump,
+        has_one = authority @ StealError::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
+    
+    pub authority: Signer<'info>,
 }
 
 #[cfg(any(not(test), feature = "idl-build"))]
+#[event_cpi]
 #[derive(Accounts)]
-pub struct BeastBuybackBurn<'info> {
+pub struct EmergencyRefund<'info> {
     #[account(
         mut,
-        seeds = [b"game"],
-        bump = game.bump
+        seeds = [b"game", game.id_seed().as_ref()],
+        bump = game.bump,
+        constraint = game.layout_version == GAME_LAYOUT_VERSION @ StealError::GameNotMigrated
     )]
     pub game: Account<'info, GameAccount>,
     
-    /// STEAL token mint (for burning)
-    #[account(mut, constraint = steal_mint.key() == game.steal_mint)]
-    pub steal_mint: Account<'info, anchor_spl::token::Mint>,
+    #[account(
+        seeds = [b"circuit-breaker", game.key().as_ref()],
+        bump = circuit_breaker.bump
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+    
+    /// Claim account of the sitting king (created on first credit, the caller pays its rent)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = Claim::SIZE,
+        seeds = [b"claim", game.key().as_ref(), game.current_king.as_ref()],
+        bump
+    )]
+    pub king_claim: Box<Account<'info, Claim>>,
     
-    /// Token account holding tokens to burn (burn pool)
     #[account(mut)]
-    pub burn_pool: Account<'info, TokenAccount>,
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[derive(Accounts)]
+pub struct SyncConfig<'info> {
+    #[account(
+        seeds = [b"steal-config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, GameConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"game", game.id_seed().as_ref()],
+        bump = game.bump,
+        constraint = game.layout_version == GAME_LAYOUT_VERSION @ StealError::GameNotMigrated
+    )]
+    pub game: Account<'info, GameAccount>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[derive(Accounts)]
+#[instruction(number: u64)]
+pub struct StartSeason<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Season::SIZE,
+        seeds = [b"season", game.key().as_ref(), number.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub season: Account<'info, Season>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = Leaderboard::SIZE,
+        seeds = [b"leaderboard", game.key().as_ref(), number.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+    
+    /// CHECK: Current season (required once the game has one - must be closed, i.e. empty)
+    #[account(
+        seeds = [b"season", game.key().as_ref(), game.season_number.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub previous_season: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        mut,
+        seeds = [b"game", game.id_seed().as_ref()],
+        bump = game.bump,
+        constraint = game.layout_version == GAME_LAYOUT_VERSION @ StealError::GameNotMigrated
+    )]
+    pub game: Account<'info, GameAccount>,
+    
+    #[account(
+        seeds = [b"steal-config"],
+        bump = config.bump,
+        has_one = authority @ StealError::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[derive(Accounts)]
+pub struct FundSeason<'info> {
+    #[account(
+        mut,
+        seeds = [b"season", season.game.as_ref(), season.number.to_le_bytes().as_ref()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+    
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[derive(Accounts)]
+pub struct CloseSeason<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"season", game.key().as_ref(), season.number.to_le_bytes().as_ref()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+    
+    #[account(
+        seeds = [b"leaderboard", game.key().as_ref(), season.number.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+    
+    #[account(
+        seeds = [b"game", game.id_seed().as_ref()],
+        bump = game.bump
+    )]
+    pub game: Account<'info, GameAccount>,
+    
+    #[account(
+        seeds = [b"steal-config"],
+        bump = config.bump,
+        has_one = authority @ StealError::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
+    
+    /// CHECK: Config authority, receives the undistributed part of the prize pool and the rent
+    #[account(mut)]
+    pub authority: UncheckedAccount<'info>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[derive(Accounts)]
+pub struct InitializeRoundHistory<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = RoundHistory::SIZE,
+        seeds = [b"round-history", game.key().as_ref()],
+        bump
+    )]
+    pub round_history: AccountLoader<'info, RoundHistory>,
+    
+    #[account(
+        mut,
+        seeds = [b"game", game.id_seed().as_ref()],
+        bump = game.bump,
+        constraint = game.layout_version == GAME_LAYOUT_VERSION @ StealError::GameNotMigrated
+    )]
+    pub game: Account<'info, GameAccount>,
+    
+    #[account(
+        seeds = [b"steal-config"],
+        bump = config.bump,
+        has_one = authority @ StealError::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[derive(Accounts)]
+pub struct MigrateGame<'info> {
+    /// CHECK: Game PDA of any instance - may be on an older layout Anchor can't deserialize,
+    /// validated manually (owner, discriminator, and address re-derived from its game_id)
+    #[account(mut)]
+    pub game: UncheckedAccount<'info>,
+    
+    /// Ruleset snapshotted into games migrating from before layout v10
+    #[account(
+        seeds = [b"steal-params"],
+        bump = params.bump
+    )]
+    pub params: Account<'info, GameParams>,
+    
+    /// Pays the rent for the larger account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct InitializeGame<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = GameAccount::SIZE,
+        seeds = [b"game".as_ref(), game_id_seed(game_id).as_ref()],
+        bump
+    )]
+    pub game: Account<'info, GameAccount>,
+    
+    /// Circuit breaker of the new game (steal, end_round and reset_round require it)
+    #[account(
+        init,
+        payer = authority,
+        space = CircuitBreaker::SIZE,
+        seeds = [b"circuit-breaker", game.key().as_ref()],
+        bump
+    )]
+    pub circuit_breaker: Box<Account<'info, CircuitBreaker>>,
+    
+    /// Ruleset used for the first round
+    #[account(
+        seeds = [b"steal-params"],
+        bump = params.bump
+    )]
+    pub params: Account<'info, GameParams>,
+    
+    /// Config - only its authority may create a game
+    #[account(
+        seeds = [b"steal-config"],
+        bump = config.bump,
+        constraint = is_config_authority(&config.authority, &authority.key()) @ StealError::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
+    
+    /// Game vault PDA (owned by System Program) - funded with the seeds
+    #[account(
+        mut,
+        seeds = [b"vault".as_ref(), game_id_seed(game_id).as_ref()],
+        bump
+    )]
+    pub game_vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Dev wallet address (must match config.dev_wallet)
+    #[account(constraint = dev_wallet.key() == config.dev_wallet @ StealError::InvalidAccount)]
+    pub dev_wallet: UncheckedAccount<'info>,
+    
+    /// CHECK: Beast wallet address (must match config.beast_wallet)
+    #[account(constraint = beast_wallet.key() == config.beast_wallet @ StealError::InvalidAccount)]
+    pub beast_wallet: UncheckedAccount<'info>,
+    
+    /// CHECK: STEAL token mint (must match config.steal_mint)
+    #[account(constraint = steal_mint.key() == config.steal_mint @ StealError::InvalidMint)]
+    pub steal_mint: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// Entry currency mint (omit for a SOL game)
+    pub currency_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    
+    /// Vault-owned token account for currency_mint (token games only - the vault PDA's ATA)
+    #[account(mut)]
+    pub token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    /// Authority's token account for currency_mint (token games only)
+    #[account(mut)]
+    pub authority_currency_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    /// Token program of currency_mint (token games only)
+    pub currency_token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Steal<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game.id_seed().as_ref()],
+        bump = game.bump,
+        constraint = game.layout_version == GAME_LAYOUT_VERSION @ StealError::GameNotMigrated
+    )]
+    pub game: Account<'info, GameAccount>,
+    
+    /// Circuit breaker for this game
+    #[account(
+        seeds = [b"circuit-breaker", game.key().as_ref()],
+        bump = circuit_breaker.bump
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+    
+    /// CHECK: Current season, needed for a round's first steal once the game has seasons
+    /// (empty once close_season has closed it)
+    #[account(
+        seeds = [b"season", game.key().as_ref(), game.season_number.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub season: Option<UncheckedAccount<'info>>,
+    
+    /// Leaderboard of the round's season (required once the round belongs to a season)
+    #[account(mut)]
+    pub leaderboard: Option<AccountLoader<'info, Leaderboard>>,
+    
+    /// Stealer's lifetime stats (optional - created on first use)
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = PlayerAccount::SIZE,
+        seeds = [b"player", player.key().as_ref()],
+        bump
+    )]
+    pub player_stats: Option<Box<Account<'info, PlayerAccount>>>,
+    
+    /// Dethroned king's lifetime stats (optional)
+    #[account(
+        mut,
+        seeds = [b"player", game.current_king.as_ref()],
+        bump = old_king_stats.bump
+    )]
+    pub old_king_stats: Option<Box<Account<'info, PlayerAccount>>>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    /// CHECK: Player's STEAL token account (optional - for VIP check)
+    /// We use UncheckedAccount to allow non-existent accounts for non-VIP players
+    pub player_token_account: Option<UncheckedAccount<'info>>,
+    
+    /// Game vault PDA (owned by System Program)
+    #[account(
+        mut,
+        seeds = [b"vault", game.id_seed().as_ref()],
+        bump
+    )]
+    pub game_vault: SystemAccount<'info>,
+    
+    /// CHECK: Old king's wallet - informational only, the payout is credited to
+    /// old_king_claim for the recorded king (kept so existing clients keep working)
+    pub old_king: UncheckedAccount<'info>,
+    
+    /// Claim account of the king being dethroned (required when there is one;
+    /// created on first credit, the stealer pays its rent)
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = Claim::SIZE,
+        seeds = [b"claim", game.key().as_ref(), game.current_king.as_ref()],
+        bump
+    )]
+    pub old_king_claim: Option<Box<Account<'info, Claim>>>,
+    
+    /// CHECK: Config account PDA (optional - manually validated if provided)
+    /// When provided, must be the correct PDA derived from seeds [b"steal-config"]
+    pub config: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: Dev wallet (must match config.dev_wallet if config exists, else game.dev_wallet)
+    #[account(mut)]
+    pub dev_wallet: UncheckedAccount<'info>,
+    
+    /// CHECK: Beast wallet (must match config.beast_wallet if config exists, else game.beast_wallet)
+    #[account(mut)]
+    pub beast_wallet: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// Entry currency mint (token games only)
+    pub currency_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    
+    /// Vault-owned token account for currency_mint (token games only)
+    #[account(mut)]
+    pub token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    /// Player's token account for currency_mint (token games only)
+    #[account(mut)]
+    pub player_currency_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    /// Dev wallet's token account for currency_mint (token games only)
+    #[account(mut)]
+    pub dev_currency_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    /// Beast wallet's token account for currency_mint (token games only)
+    #[account(mut)]
+    pub beast_currency_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    /// Token program of currency_mint (token games only)
+    pub currency_token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[derive(Accounts)]
+pub struct QuoteSteal<'info> {
+    /// CHECK: Game PDA of any layout version - owner and seeds verified by load_game_versioned
+    pub game: UncheckedAccount<'info>,
+    
+    /// On-chain ruleset
+    #[account(
+        seeds = [b"steal-params"],
+        bump = params.bump
+    )]
+    pub params: Account<'info, GameParams>,
+    
+    /// Circuit breaker for this game
+    #[account(
+        seeds = [b"circuit-breaker", game.key().as_ref()],
+        bump = circuit_breaker.bump
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+    
+    /// CHECK: Player's STEAL token account (optional - for VIP check, validated like in steal)
+    pub player_token_account: Option<UncheckedAccount<'info>>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EndRound<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game.id_seed().as_ref()],
+        bump = game.bump,
+        constraint = game.layout_version == GAME_LAYOUT_VERSION @ StealError::GameNotMigrated
+    )]
+    pub game: Account<'info, GameAccount>,
+    
+    /// Ruleset (mut: a staged ruleset is applied at the round boundary)
+    #[account(
+        mut,
+        seeds = [b"steal-params"],
+        bump = params.bump
+    )]
+    pub params: Account<'info, GameParams>,
+    
+    /// Circuit breaker for this game
+    #[account(
+        seeds = [b"circuit-breaker", game.key().as_ref()],
+        bump = circuit_breaker.bump
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+    
+    /// Game vault PDA (owned by System Program)
+    #[account(
+        mut,
+        seeds = [b"vault", game.id_seed().as_ref()],
+        bump
+    )]
+    pub game_vault: SystemAccount<'info>,
+    
+    /// CHECK: Winner's wallet (must be current king / recent_kings[0]) - not paid here,
+    /// the payout is recorded in `prize`
+    #[account(constraint = winner.key() == game.current_king)]
+    pub winner: UncheckedAccount<'info>,
+    
+    /// Claimable payouts of this round, created at settlement
+    #[account(
+        init,
+        payer = payer,
+        space = RoundPrize::SIZE,
+        seeds = [b"prize", game.key().as_ref(), game.round.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub prize: Box<Account<'info, RoundPrize>>,
+    
+    /// Crank paying the prize record's rent (returned once every place is claimed)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: Config account PDA (optional - manually validated if provided)
+    /// When provided, must be the correct PDA derived from seeds [b"steal-config"]
+    pub config: Option<UncheckedAccount<'info>>,
+    
+    /// Leaderboard of the round's season (required if the round belongs to a season)
+    #[account(mut)]
+    pub leaderboard: Option<AccountLoader<'info, Leaderboard>>,
+    
+    /// Round history of this game (required once it exists)
+    #[account(mut)]
+    pub round_history: Option<AccountLoader<'info, RoundHistory>>,
+    
+    /// Lifetime stats of the podium (optional, 1st to 3rd)
+    #[account(
+        mut,
+        seeds = [b"player", winner.key().as_ref()],
+        bump = winner_stats.bump
+    )]
+    pub winner_stats: Option<Box<Account<'info, PlayerAccount>>>,
+    
+    #[account(
+        mut,
+        seeds = [b"player", game.recent_kings[1].as_ref()],
+        bump = winner2_stats.bump
+    )]
+    pub winner2_stats: Option<Box<Account<'info, PlayerAccount>>>,
+    
+    #[account(
+        mut,
+        seeds = [b"player", game.recent_kings[2].as_ref()],
+        bump = winner3_stats.bump
+    )]
+    pub winner3_stats: Option<Box<Account<'info, PlayerAccount>>>,
+    
+    /// CHECK: Dev wallet (must match config.dev_wallet if config exists, else game.dev_wallet)
+    #[account(mut)]
+    pub dev_wallet: UncheckedAccount<'info>,
+    
+    /// CHECK: Beast wallet (must match config.beast_wallet if config exists, else game.beast_wallet)
+    #[account(mut)]
+    pub beast_wallet: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ResetRound<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game.id_seed().as_ref()],
+        bump = game.bump,
+        constraint = game.layout_version == GAME_LAYOUT_VERSION @ StealError::GameNotMigrated
+    )]
+    pub game: Account<'info, GameAccount>,
+    
+    /// Ruleset (mut: a staged ruleset is applied at the round boundary)
+    #[account(
+        mut,
+        seeds = [b"steal-params"],
+        bump = params.bump
+    )]
+    pub params: Account<'info, GameParams>,
+    
+    /// Circuit breaker for this game
+    #[account(
+        seeds = [b"circuit-breaker", game.key().as_ref()],
+        bump = circuit_breaker.bump
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+    
+    /// Round history of this game (required once it exists)
+    #[account(mut)]
+    pub round_history: Option<AccountLoader<'info, RoundHistory>>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AuditVault<'info> {
+    /// CHECK: Game PDA of any layout version - owner and seeds verified by load_game_versioned
+    pub game: UncheckedAccount<'info>,
+    
+    /// Game vault PDA (owned by System Program - seeds verified in the handler)
+    pub game_vault: SystemAccount<'info>,
+    
+    /// Entry currency mint (token games only)
+    pub currency_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    
+    /// Vault-owned token account for currency_mint (token games only)
+    pub token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    /// Token program of currency_mint (token games only)
+    pub currency_token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SweepSurplus<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game.id_seed().as_ref()],
+        bump = game.bump,
+        constraint = game.layout_version == GAME_LAYOUT_VERSION @ StealError::GameNotMigrated
+    )]
+    pub game: Account<'info, GameAccount>,
+    
+    /// Game vault PDA (owned by System Program)
+    #[account(
+        mut,
+        seeds = [b"vault", game.id_seed().as_ref()],
+        bump
+    )]
+    pub game_vault: SystemAccount<'info>,
+    
+    #[account(
+        seeds = [b"steal-config"],
+        bump = config.bump,
+        has_one = authority @ StealError::Unauthorized
+    )]
+    pub config: Account<'info, GameConfig>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Dev wallet (must match config.dev_wallet)
+    #[account(mut, constraint = dev_wallet.key() == config.dev_wallet @ StealError::InvalidAccount)]
+    pub dev_wallet: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// Entry currency mint (token games only)
+    pub currency_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    
+    /// Vault-owned token account for currency_mint (token games only)
+    #[account(mut)]
+    pub token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    /// Dev wallet's token account for currency_mint (token games only)
+    #[account(mut)]
+    pub dev_currency_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    /// Token program of currency_mint (token games only)
+    pub currency_token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimPayout<'info> {
+    #[account(
+        mut,
+        seeds = [b"claim", game.key().as_ref(), wallet.key().as_ref()],
+        bump = claim.bump
+    )]
+    pub claim: Account<'info, Claim>,
+    
+    #[account(
+        mut,
+        seeds = [b"game", game.id_seed().as_ref()],
+        bump = game.bump,
+        constraint = game.layout_version == GAME_LAYOUT_VERSION @ StealError::GameNotMigrated
+    )]
+    pub game: Account<'info, GameAccount>,
+    
+    /// Game vault PDA (owned by System Program)
+    #[account(
+        mut,
+        seeds = [b"vault", game.id_seed().as_ref()],
+        bump
+    )]
+    pub game_vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// Entry currency mint (token games only)
+    pub currency_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    
+    /// Vault-owned token account for currency_mint (token games only)
+    #[account(mut)]
+    pub token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    /// Wallet's token account for currency_mint (token games only)
+    #[account(mut)]
+    pub wallet_currency_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    /// Token program of currency_mint (token games only)
+    pub currency_token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(round: u64)]
+pub struct ClaimPrize<'info> {
+    #[account(
+        mut,
+        seeds = [b"prize", game.key().as_ref(), round.to_le_bytes().as_ref()],
+        bump = prize.bump
+    )]
+    pub prize: Account<'info, RoundPrize>,
+    
+    #[account(
+        mut,
+        seeds = [b"game", game.id_seed().as_ref()],
+        bump = game.bump,
+        constraint = game.layout_version == GAME_LAYOUT_VERSION @ StealError::GameNotMigrated
+    )]
+    pub game: Account<'info, GameAccount>,
+    
+    /// Game vault PDA (owned by System Program)
+    #[account(
+        mut,
+        seeds = [b"vault", game.id_seed().as_ref()],
+        bump
+    )]
+    pub game_vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    
+    /// CHECK: Receives the prize record's rent when it is closed (must match prize.rent_payer)
+    #[account(mut, constraint = rent_payer.key() == prize.rent_payer @ StealError::InvalidAccount)]
+    pub rent_payer: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// Entry currency mint (token games only)
+    pub currency_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    
+    /// Vault-owned token account for currency_mint (token games only)
+    #[account(mut)]
+    pub token_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    /// Wallet's token account for currency_mint (token games only)
+    #[account(mut)]
+    pub wallet_currency_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    
+    /// Token program of currency_mint (token games only)
+    pub currency_token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BeastBuybackBurn<'info> {
+    #[account(
+        mut,
+        seeds = [b"game", game.id_seed().as_ref()],
+        bump = game.bump,
+        constraint = game.layout_version == GAME_LAYOUT_VERSION @ StealError::GameNotMigrated
+    )]
+    pub game: Account<'info, GameAccount>,
+    
+    /// STEAL token mint (for burning) - Token or Token-2022
+    #[account(
+        mut,
+        constraint = steal_mint.key() == game.steal_mint,
+        mint::token_program = token_program
+    )]
+    pub steal_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Token account holding tokens to burn (burn pool)
+    #[account(
+        mut,
+        token::mint = steal_mint,
+        token::token_program = token_program
+    )]
+    pub burn_pool: InterfaceAccount<'info, TokenAccount>,
     
     /// Authority that can burn from burn_pool
     pub burn_authority: Signer<'info>,
@@ -1151,5 +3100,5 @@ pub struct BeastBuybackBurn<'info> {
     #[account(mut, constraint = beast_wallet.key() == game.beast_wallet)]
     pub beast_wallet: UncheckedAccount<'info>,
     
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
@@ -3,8 +3,10 @@
 // ============================================================================
 
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::TokenAccount;
 use crate::constants::*;
-use crate::state::GameState;
+use crate::state::{GameState, LeaderboardEntry, RoundRecord, RoundSettlement, Ruleset, StakeTier, StealQuote, StealSnapshot, VaultLedger};
 use crate::errors::StealError;
 
 // ============================================================================
@@ -19,8 +21,32 @@ pub fn calculate_entry_cost(token_balance: u64, game_price: u64) -> u64 {
         .unwrap_or(0)
 }
 
-pub fn is_vip(token_balance: u64) -> bool {
-    token_balance >= TOKEN_REQUIREMENT
+pub fn is_vip(token_balance: u64, rules: &Ruleset) -> bool {
+    token_balance >= rules.token_requirement
+}
+
+/// $STEAL balance that counts toward VIP - a frozen account counts as empty
+pub fn vip_token_balance(amount: u64, is_frozen: bool) -> u64 {
+    if is_frozen {
+        0
+    } else {
+        amount
+    }
+}
+
+/// $STEAL balance a player's token account counts toward VIP
+/// The account must belong to the Token or Token-2022 program (extensions are parsed),
+/// hold `steal_mint` and be owned by `player`; a frozen account counts as empty
+pub fn vip_account_balance(program: &Pubkey, data: &[u8], steal_mint: &Pubkey, player: &Pubkey) -> Result<u64> {
+    require!(
+        *program == anchor_spl::token::ID || *program == anchor_spl::token_2022::ID,
+        StealError::InvalidTokenAccount
+    );
+    let account = TokenAccount::try_deserialize(&mut &data[..])
+        .map_err(|_| StealError::InvalidTokenAccount)?;
+    require!(account.mint == *steal_mint, StealError::InvalidMint);
+    require!(account.owner == *player, StealError::InvalidTokenAccountOwner);
+    Ok(vip_token_balance(account.amount, account.is_frozen()))
 }
 
 pub fn execute_entry(token_balance: u64, game_price: u64) -> (u64, u64, u64) {
@@ -37,18 +63,163 @@ pub fn verify_token_mint(user_mint: Pubkey, real_mint: Pubkey) -> Result<()> {
     }
 }
 
+// ============================================================================
+// ADMIN TIMELOCK
+// ============================================================================
+
+/// Earliest time a change queued at `queued_at` may be executed
+pub fn change_eta(queued_at: i64) -> i64 {
+    queued_at.saturating_add(CHANGE_TIMELOCK_SECS)
+}
+
+/// Check a queued change's timelock has expired at `now`
+pub fn check_change_ready(now: i64, eta: i64) -> Result<()> {
+    require!(now >= eta, StealError::TimelockNotExpired);
+    Ok(())
+}
+
+/// Step 2 of the authority handover: only the proposed wallet can accept, and the
+/// proposal is consumed - from then on has_one = authority only admits `signer`
+pub fn accept_pending_authority(authority: &mut Pubkey, pending_authority: &mut Pubkey, signer: Pubkey) -> Result<()> {
+    require!(
+        *pending_authority != Pubkey::default() && *pending_authority == signer,
+        StealError::InvalidPendingAuthority
+    );
+    *authority = signer;
+    *pending_authority = Pubkey::default();
+    Ok(())
+}
+
+// ============================================================================
+// RULESET VALIDATION
+// ============================================================================
+
+/// Check the invariants a ruleset must satisfy before set_params will stage it
+/// - Run It Up surplus splits must account for exactly 100% of profit_delta
+/// - Hit A Lick overhead (incl. old king refund) and round end payouts may not exceed 100%
+/// - Round end dev/beast shares must be 0 (end_round pays winners and rollover only)
+/// - Timers, clamps and rates must be usable as divisors / ordered ranges
+pub fn validate_ruleset(rules: &Ruleset) -> Result<()> {
+    let surplus_total = rules.surplus_dev
+        .checked_add(rules.surplus_beast)
+        .and_then(|v| v.checked_add(rules.surplus_yield))
+        .and_then(|v| v.checked_add(rules.surplus_jackpot))
+        .ok_or(StealError::Overflow)?;
+    require!(surplus_total == 1000, StealError::InvalidSplit);
+    
+    let hit_a_lick_total = rules.hit_a_lick_old_king_refund
+        .checked_add(rules.hit_a_lick_dev)
+        .and_then(|v| v.checked_add(rules.hit_a_lick_beast))
+        .and_then(|v| v.checked_add(rules.hit_a_lick_yield))
+        .and_then(|v| v.checked_add(rules.hit_a_lick_jackpot))
+        .ok_or(StealError::Overflow)?;
+    require!(hit_a_lick_total <= 1000, StealError::InvalidSplit);
+    
+    let end_total = rules.end_winner_1
+        .checked_add(rules.end_winner_2)
+        .and_then(|v| v.checked_add(rules.end_winner_3))
+        .and_then(|v| v.checked_add(rules.end_dev))
+        .and_then(|v| v.checked_add(rules.end_beast))
+        .and_then(|v| v.checked_add(rules.end_rollover))
+        .ok_or(StealError::Overflow)?;
+    require!(end_total <= 1000, StealError::InvalidSplit);
+    
+    let end_mega_total = rules.end_winner_1_mega
+        .checked_add(rules.end_winner_2_mega)
+        .and_then(|v| v.checked_add(rules.end_winner_3_mega))
+        .and_then(|v| v.checked_add(rules.end_dev_mega))
+        .and_then(|v| v.checked_add(rules.end_beast_mega))
+        .and_then(|v| v.checked_add(rules.end_rollover_mega))
+        .ok_or(StealError::Overflow)?;
+    require!(end_mega_total <= 1000, StealError::InvalidSplit);
+    // end_round has no dev/beast payout path - those fees come from entries only,
+    // so a non-zero pot share would be stranded in the vault
+    require!(
+        rules.end_dev == 0 && rules.end_beast == 0
+            && rules.end_dev_mega == 0 && rules.end_beast_mega == 0,
+        StealError::InvalidSplit
+    );
+    
+    // Price must actually rise in Run It Up (rate is a percentage, 112 = +12%)
+    require!(rules.run_it_up_rate > 100, StealError::InvalidParams);
+    // Timers: round timer must be positive and a single steal can't add more than the cap
+    require!(rules.max_timer > 0, StealError::InvalidParams);
+    require!(rules.timer_add <= rules.max_timer, StealError::InvalidParams);
+    require!(rules.hit_a_lick_timer > 0, StealError::InvalidParams);
+    // Hit A Lick trigger and price clamps
+    require!(rules.hit_a_lick_threshold > 0, StealError::InvalidParams);
+    require!(rules.hit_a_lick_pct_of_pot <= 1000, StealError::InvalidParams);
+    require!(rules.hit_a_lick_surcharge <= 1000, StealError::InvalidParams);
+    // Yield: time bonus cap is used as a divisor, ROI cap is out of 1000
+    require!(rules.yield_time_bonus_cap_secs > 0, StealError::InvalidParams);
+    require!(rules.yield_base_rate <= 1000, StealError::InvalidParams);
+    require!(rules.yield_cap <= 1000, StealError::InvalidParams);
+    // Emergency refund must stay reachable
+    require!(rules.emergency_refund_grace_secs > 0, StealError::InvalidParams);
+    
+    Ok(())
+}
+
+/// Promote a staged ruleset into `active` (GameParams::apply_staged)
+/// Returns true if a new ruleset took effect
+pub fn promote_staged_rules(
+    active: &mut Ruleset,
+    staged: &Ruleset,
+    has_staged: &mut bool,
+    version: &mut u64,
+) -> bool {
+    if !*has_staged {
+        return false;
+    }
+    *active = *staged;
+    *has_staged = false;
+    *version = version.saturating_add(1);
+    true
+}
+
+/// Validate a table's stakes before a game instance is created with them
+pub fn validate_stake_tier(tier: &StakeTier) -> Result<()> {
+    require!(tier.start_price > 0, StealError::InvalidParams);
+    require!(
+        tier.hit_a_lick_min > 0 && tier.hit_a_lick_min <= tier.hit_a_lick_max,
+        StealError::InvalidParams
+    );
+    require!(tier.mega_pot_threshold > 0, StealError::InvalidParams);
+    Ok(())
+}
+
 // ============================================================================
 // GAME INITIALIZATION
 // ============================================================================
 
-pub fn new_game() -> GameState {
+/// Whether `signer` is the config authority (only it may create a game)
+pub fn is_config_authority(authority: &Pubkey, signer: &Pubkey) -> bool {
+    *authority != Pubkey::default() && authority == signer
+}
+
+/// Vault balance initialize_game must leave behind: both seeds on top of the vault's
+/// own reserve, so the books and the vault agree from the first block
+pub fn required_seed_balance(jackpot_seed: u64, yield_seed: u64, vault_reserve: u64) -> Result<u64> {
+    jackpot_seed
+        .checked_add(yield_seed)
+        .and_then(|v| v.checked_add(vault_reserve))
+        .ok_or_else(|| StealError::Overflow.into())
+}
+
+/// Check the vault holds the seeds after the authority's top-up
+pub fn check_seed_funded(vault_balance: u64, required: u64) -> Result<()> {
+    require!(vault_balance >= required, StealError::InsufficientVaultBalance);
+    Ok(())
+}
+
+pub fn new_game(tier: &StakeTier, rules: &Ruleset) -> GameState {
     GameState {
-        current_price: START_PRICE,
+        current_price: tier.start_price,
         hit_a_lick_price: 0,
         jackpot_balance: 0,
         pending_jackpot: 0,
         yield_pool: 0,
-        time_remaining: MAX_TIMER,
+        time_remaining: rules.max_timer,
         hit_a_lick_end_time: 0,
         is_hit_a_lick_mode: false,
         current_king: None,
@@ -59,10 +230,12 @@ pub fn new_game() -> GameState {
         recent_kings_count: 0,
         last_steal_wallet: None,
         last_steal_time: 0,
-        cooldown_seconds: HIT_A_LICK_COOLDOWN_SECS,
+        cooldown_seconds: rules.hit_a_lick_cooldown_secs,
         growth_steals: 0,
         min_growth_steals_for_war: MIN_GROWTH_STEALS_CLAMP,
         growth_hard_end_ts: 0,
+        dust: 0,
+        tier: *tier,
     }
 }
 
@@ -70,40 +243,43 @@ pub fn new_game() -> GameState {
 // HIT A LICK MODE PRICE CALCULATION
 // ============================================================================
 
-/// Calculate hit a lick price: 3% of pot, clamped between 0.05 and 1.50 SOL
-pub fn calculate_hit_a_lick_price(jackpot: u64) -> u64 {
+/// Calculate hit a lick price: 3% of pot, clamped to the tier's [min, max] (0.05-1.50 SOL by default)
+pub fn calculate_hit_a_lick_price(jackpot: u64, tier: &StakeTier, rules: &Ruleset) -> u64 {
     // Use checked multiplication to prevent overflow
     let raw_price = jackpot
-        .checked_mul(HIT_A_LICK_PCT_OF_POT)
+        .checked_mul(rules.hit_a_lick_pct_of_pot)
         .and_then(|v| v.checked_div(1000))
-        .unwrap_or(u64::MAX); // On overflow, use max value which will clamp to HIT_A_LICK_MAX
-    raw_price.clamp(HIT_A_LICK_MIN, HIT_A_LICK_MAX)
+        .unwrap_or(u64::MAX); // On overflow, use max value which will clamp to hit_a_lick_max
+    raw_price.clamp(tier.hit_a_lick_min, tier.hit_a_lick_max)
 }
 
 /// Calculate minimum growth steals required before war can trigger
-/// Formula: 35 base + 15 steals per SOL in pot, clamped to [35, 100]
+/// Formula: 35 base + 15 steals per whole unit in pot (1 SOL, or 1 token at the mint's
+/// decimals), clamped to [35, 100]
 /// This ensures jackpot growth offsets winner payouts (16% of pot) and prevents drainage
-pub fn compute_min_growth_steals_for_war(jackpot_lamports: u64) -> u16 {
-    // Base steals + (pot_in_lamports * 15) / LAMPORTS_PER_SOL
+pub fn compute_min_growth_steals_for_war(jackpot: u64, decimals: u8) -> u16 {
+    // Base steals + (pot_in_base_units * 15) / one_whole_unit
     // This formula ensures exponential pot growth offsets winner payouts
-    let additional_steals = jackpot_lamports
-        .saturating_mul(GROWTH_STEALS_PER_SOL)
-        .saturating_div(LAMPORTS_PER_SOL);
-    let total = GROWTH_STEALS_BASE as u64 + additional_steals;
-    (total as u16).max(MIN_GROWTH_STEALS_CLAMP).min(MAX_GROWTH_STEALS_CLAMP)
+    let additional_steals = match 10u128.checked_pow(decimals as u32) {
+        Some(one_unit) => (jackpot as u128 * GROWTH_STEALS_PER_SOL as u128) / one_unit,
+        // A whole unit is beyond any u64 pot - the pot never adds steals
+        None => 0,
+    };
+    let total = (GROWTH_STEALS_BASE as u128 + additional_steals).min(MAX_GROWTH_STEALS_CLAMP as u128);
+    (total as u16).max(MIN_GROWTH_STEALS_CLAMP)
 }
 
 // ============================================================================
 // PHASE TRANSITIONS
 // ============================================================================
 
-pub fn check_phase_transition(game: &mut GameState) {
+pub fn check_phase_transition(game: &mut GameState, rules: &Ruleset) {
     // Only check for hit a lick mode transition if not already in hit a lick mode
     // AND there's actually a jackpot to fight over
     if !game.is_hit_a_lick_mode && game.jackpot_balance > 0 {
         // Use checked arithmetic to prevent overflow
         let hit_a_lick_threshold_price = game.jackpot_balance
-            .checked_mul(HIT_A_LICK_THRESHOLD)
+            .checked_mul(rules.hit_a_lick_threshold)
             .and_then(|v| v.checked_div(100))
             .unwrap_or(u64::MAX); // On overflow, use max (will never trigger hit a lick)
         
@@ -113,16 +289,16 @@ pub fn check_phase_transition(game: &mut GameState) {
         // Hit A Lick mode triggers when EITHER price threshold (60% of jackpot) OR minimum steals are met
         // This allows flame bar (price progress) to trigger hit a lick mode when full
         if price_triggers || steals_trigger {
-            trigger_hit_a_lick_mode(game);
+            trigger_hit_a_lick_mode(game, rules);
         }
     }
 }
 
-pub fn trigger_hit_a_lick_mode(game: &mut GameState) {
+pub fn trigger_hit_a_lick_mode(game: &mut GameState, rules: &Ruleset) {
     game.is_hit_a_lick_mode = true;
     // Calculate hit a lick price: 3% of pot, clamped [0.05, 1.50] SOL
-    game.hit_a_lick_price = calculate_hit_a_lick_price(game.jackpot_balance);
-    game.hit_a_lick_end_time = HIT_A_LICK_TIMER;
+    game.hit_a_lick_price = calculate_hit_a_lick_price(game.jackpot_balance, &game.tier, rules);
+    game.hit_a_lick_end_time = rules.hit_a_lick_timer;
     // Reset recent kings for new War
     game.recent_kings = [Pubkey::default(); 3];
     game.recent_kings_count = 0;
@@ -166,14 +342,28 @@ pub fn update_recent_kings(recent_kings: &mut [Pubkey; 3], count: &mut u8, new_k
     }
 }
 
+/// Drop `king` from the recent_kings array, closing the gap (no-op if absent)
+/// Used when a king is made whole outside the podium (emergency_refund)
+pub fn remove_recent_king(recent_kings: &mut [Pubkey; 3], count: &mut u8, king: Pubkey) {
+    let len = (*count as usize).min(3);
+    if let Some(idx) = recent_kings[..len].iter().position(|k| *k == king) {
+        for i in idx..2 {
+            recent_kings[i] = recent_kings[i + 1];
+        }
+        recent_kings[2] = Pubkey::default();
+        *count -= 1;
+    }
+}
+
 // ============================================================================
 // RUN IT UP MODE ECONOMICS
 // ============================================================================
 
-/// Calculate run it up mode split - returns (refund, dev, beast, yield_add, jackpot)
+/// Calculate run it up mode split - returns (refund, dev, beast, yield_add, jackpot, dust)
 /// Note: Old king gets REFUND ONLY (no profit), yield is calculated separately
 /// Taxes are calculated on profit_delta (new_price - old_price), not old_price
-pub fn calculate_run_it_up_split(old_price: u64, new_price: u64) -> (u64, u64, u64, u64, u64) {
+/// `dust` is the rounding remainder, so refund + dev + beast + yield_add + jackpot + dust == new_price
+pub fn calculate_run_it_up_split(old_price: u64, new_price: u64, rules: &Ruleset) -> (u64, u64, u64, u64, u64, u64) {
     let refund = old_price;
     
     // Calculate profit delta (the 12% increase)
@@ -182,23 +372,28 @@ pub fn calculate_run_it_up_split(old_price: u64, new_price: u64) -> (u64, u64, u
     // Use checked arithmetic to prevent overflow
     // Taxes are now based on profit_delta, not old_price
     let dev = profit_delta
-        .checked_mul(SURPLUS_DEV)
+        .checked_mul(rules.surplus_dev)
         .and_then(|v| v.checked_div(1000))
         .unwrap_or(0);
     let beast = profit_delta
-        .checked_mul(SURPLUS_BEAST)
+        .checked_mul(rules.surplus_beast)
         .and_then(|v| v.checked_div(1000))
         .unwrap_or(0);
     let yield_add = profit_delta
-        .checked_mul(SURPLUS_YIELD)
+        .checked_mul(rules.surplus_yield)
         .and_then(|v| v.checked_div(1000))
         .unwrap_or(0);
     let jackpot = profit_delta
-        .checked_mul(SURPLUS_JACKPOT)
+        .checked_mul(rules.surplus_jackpot)
         .and_then(|v| v.checked_div(1000))
         .unwrap_or(0);
+    let dust = profit_delta
+        .saturating_sub(dev)
+        .saturating_sub(beast)
+        .saturating_sub(yield_add)
+        .saturating_sub(jackpot);
     
-    (refund, dev, beast, yield_add, jackpot)
+    (refund, dev, beast, yield_add, jackpot, dust)
 }
 
 // ============================================================================
@@ -206,13 +401,13 @@ pub fn calculate_run_it_up_split(old_price: u64, new_price: u64) -> (u64, u64, u
 // ============================================================================
 
 /// Calculate hit a lick entry cost: hit_a_lick_price + 12% surcharge (VIP pays no surcharge)
-pub fn calculate_hit_a_lick_entry_cost(hit_a_lick_price: u64, is_vip: bool) -> u64 {
+pub fn calculate_hit_a_lick_entry_cost(hit_a_lick_price: u64, is_vip: bool, rules: &Ruleset) -> u64 {
     if is_vip && VIP_NO_SURCHARGE {
         // VIP pays no surcharge
         hit_a_lick_price
     } else {
         let surcharge = hit_a_lick_price
-            .checked_mul(HIT_A_LICK_SURCHARGE)
+            .checked_mul(rules.hit_a_lick_surcharge)
             .and_then(|v| v.checked_div(1000))
             .unwrap_or(0);
         hit_a_lick_price.saturating_add(surcharge)
@@ -220,28 +415,42 @@ pub fn calculate_hit_a_lick_entry_cost(hit_a_lick_price: u64, is_vip: bool) -> u
 }
 
 /// Calculate hit a lick old king payout: 90% refund of hit_a_lick_price
-pub fn calculate_hit_a_lick_old_king_payout(hit_a_lick_price: u64) -> u64 {
+pub fn calculate_hit_a_lick_old_king_payout(hit_a_lick_price: u64, rules: &Ruleset) -> u64 {
     hit_a_lick_price
-        .checked_mul(HIT_A_LICK_OLD_KING_REFUND)
+        .checked_mul(rules.hit_a_lick_old_king_refund)
         .and_then(|v| v.checked_div(1000))
         .unwrap_or(0)
 }
 
-/// Calculate hit a lick overhead split - returns (dev, beast, yield, jackpot)
-/// New rules: 5% dev, 5% jackpot, 0% beast, 0% yield
-pub fn calculate_hit_a_lick_overhead_split(hit_a_lick_price: u64) -> (u64, u64, u64, u64) {
+/// Calculate hit a lick overhead split - returns (dev, beast, yield, jackpot, dust)
+/// Default rules: 5% dev, 5% jackpot, 0% beast, 0% yield
+/// `dust` is what the reserved refund share (90% of hit_a_lick_price) and the overhead shares
+/// leave of hit_a_lick_price; plan_steal reconciles that share with the refund actually credited
+pub fn calculate_hit_a_lick_overhead_split(hit_a_lick_price: u64, rules: &Ruleset) -> (u64, u64, u64, u64, u64) {
     // Use checked arithmetic to prevent overflow
     let dev = hit_a_lick_price
-        .checked_mul(HIT_A_LICK_DEV)
+        .checked_mul(rules.hit_a_lick_dev)
+        .and_then(|v| v.checked_div(1000))
+        .unwrap_or(0);
+    let beast = hit_a_lick_price
+        .checked_mul(rules.hit_a_lick_beast)
+        .and_then(|v| v.checked_div(1000))
+        .unwrap_or(0);
+    let yield_add = hit_a_lick_price
+        .checked_mul(rules.hit_a_lick_yield)
         .and_then(|v| v.checked_div(1000))
         .unwrap_or(0);
-    let beast = 0; // No beast fee in Hit A Lick mode
-    let yield_add = 0; // No yield pool in Hit A Lick mode
     let jackpot = hit_a_lick_price
-        .checked_mul(HIT_A_LICK_JACKPOT)
+        .checked_mul(rules.hit_a_lick_jackpot)
         .and_then(|v| v.checked_div(1000))
         .unwrap_or(0);
-    (dev, beast, yield_add, jackpot)
+    let dust = hit_a_lick_price
+        .saturating_sub(calculate_hit_a_lick_old_king_payout(hit_a_lick_price, rules))
+        .saturating_sub(dev)
+        .saturating_sub(beast)
+        .saturating_sub(yield_add)
+        .saturating_sub(jackpot);
+    (dev, beast, yield_add, jackpot, dust)
 }
 
 // ============================================================================
@@ -250,23 +459,23 @@ pub fn calculate_hit_a_lick_overhead_split(hit_a_lick_price: u64) -> (u64, u64,
 
 /// Calculate time bonus: 1.0 → 2.0 over 30 seconds
 /// Returns value out of 1000 (1000 = 1.0x, 2000 = 2.0x)
-pub fn calculate_time_bonus(hold_time_seconds: u64) -> u64 {
+pub fn calculate_time_bonus(hold_time_seconds: u64, rules: &Ruleset) -> u64 {
     // Linear interpolation: 1.0 at 0s, 2.0 at 30s, capped at 2.0
-    let bonus_factor = hold_time_seconds.min(YIELD_TIME_BONUS_CAP_SECS) * 1000 / YIELD_TIME_BONUS_CAP_SECS;
+    let bonus_factor = hold_time_seconds.min(rules.yield_time_bonus_cap_secs) * 1000 / rules.yield_time_bonus_cap_secs;
     1000 + bonus_factor  // 1000 (1.0x) + up to 1000 (another 1.0x)
 }
 
 /// Calculate yield based on new payment and time held
 /// raw_yield = 1% of new_payment × time_bonus
 /// VIP gets double yield (multiplied by VIP_YIELD_MULTIPLIER)
-pub fn calculate_yield(new_payment: u64, hold_time_seconds: u64, is_vip: bool) -> u64 {
-    let time_bonus = calculate_time_bonus(hold_time_seconds);
+pub fn calculate_yield(new_payment: u64, hold_time_seconds: u64, is_vip: bool, rules: &Ruleset) -> u64 {
+    let time_bonus = calculate_time_bonus(hold_time_seconds, rules);
     // raw_yield = 1% of new_payment × time_bonus
     // = new_payment × 0.01 × (1.0 to 2.0)
-    // = new_payment × YIELD_BASE_RATE / 1000 × time_bonus / 1000
+    // = new_payment × rules.yield_base_rate / 1000 × time_bonus / 1000
     // Use checked arithmetic to prevent overflow
     let base_yield = new_payment
-        .checked_mul(YIELD_BASE_RATE)
+        .checked_mul(rules.yield_base_rate)
         .and_then(|v| v.checked_div(1000))
         .and_then(|v| v.checked_mul(time_bonus))
         .and_then(|v| v.checked_div(1000))
@@ -288,13 +497,14 @@ pub fn calculate_yield_with_cap(
     entry_price: u64,
     new_payment: u64,
     hold_time_seconds: u64,
-    is_vip: bool
+    is_vip: bool,
+    rules: &Ruleset
 ) -> u64 {
-    let raw_yield = calculate_yield(new_payment, hold_time_seconds, is_vip);
+    let raw_yield = calculate_yield(new_payment, hold_time_seconds, is_vip, rules);
     
     // Cap 1: max 50% ROI on entry price (use checked arithmetic)
     let cap_by_entry = entry_price
-        .checked_mul(YIELD_CAP)
+        .checked_mul(rules.yield_cap)
         .and_then(|v| v.checked_div(1000))
         .unwrap_or(0); // On overflow, cap at 0 (safe fallback)
     
@@ -308,38 +518,40 @@ pub fn calculate_yield_with_cap(
 // STEAL EXECUTION
 // ============================================================================
 
-pub fn execute_steal(game: &mut GameState) -> Result<()> {
+pub fn execute_steal(game: &mut GameState, rules: &Ruleset) -> Result<()> {
     if game.is_hit_a_lick_mode {
         // Hit A Lick mode: price frozen, timer reset to 30 seconds
-        game.hit_a_lick_end_time = HIT_A_LICK_TIMER;
+        game.hit_a_lick_end_time = rules.hit_a_lick_timer;
         
         // Add yield and jackpot from overhead split
-        let (_, _, yield_add, jackpot_add) = calculate_hit_a_lick_overhead_split(game.hit_a_lick_price);
+        let (_, _, yield_add, jackpot_add, dust) = calculate_hit_a_lick_overhead_split(game.hit_a_lick_price, rules);
         game.yield_pool += yield_add;
-        game.pending_jackpot += jackpot_add;
+        game.pending_jackpot += jackpot_add + dust;
+        game.dust += dust;
     } else {
         // Run It Up mode: store old price before updating
         let old_price = game.current_price;
         
         // Increase price by 12% (use checked arithmetic)
         game.current_price = game.current_price
-            .checked_mul(RUN_IT_UP_RATE)
+            .checked_mul(rules.run_it_up_rate)
             .and_then(|v| v.checked_div(100))
             .unwrap_or(u64::MAX); // On overflow, cap at max (unlikely but safe)
         
         // Add 10 seconds (cap at 10 minutes)
-        game.time_remaining += TIMER_ADD;
-        if game.time_remaining > MAX_TIMER {
-            game.time_remaining = MAX_TIMER;
+        game.time_remaining += rules.timer_add;
+        if game.time_remaining > rules.max_timer {
+            game.time_remaining = rules.max_timer;
         }
         
         // Calculate new price
         let new_price = game.current_price;
         
         // Update jackpot and yield pool from run it up split
-        let (_, _, _, yield_add, jackpot_add) = calculate_run_it_up_split(old_price, new_price);
+        let (_, _, _, yield_add, jackpot_add, dust) = calculate_run_it_up_split(old_price, new_price, rules);
         game.yield_pool += yield_add;
-        game.pending_jackpot += jackpot_add;
+        game.pending_jackpot += jackpot_add + dust;
+        game.dust += dust;
         
         // Increment growth steals counter
         game.growth_steals = game.growth_steals.saturating_add(1);
@@ -349,7 +561,7 @@ pub fn execute_steal(game: &mut GameState) -> Result<()> {
         if game.jackpot_balance > 0 {
             // Use checked arithmetic to prevent overflow
             let hit_a_lick_threshold_price = game.jackpot_balance
-                .checked_mul(HIT_A_LICK_THRESHOLD)
+                .checked_mul(rules.hit_a_lick_threshold)
                 .and_then(|v| v.checked_div(100))
                 .unwrap_or(u64::MAX); // On overflow, use max (will never trigger hit a lick)
             
@@ -361,7 +573,7 @@ pub fn execute_steal(game: &mut GameState) -> Result<()> {
             // Hit A Lick mode triggers when EITHER price threshold (60% of jackpot) OR minimum steals are met
             // This allows flame bar (price progress) to trigger hit a lick mode when full
             if price_triggers || steals_trigger {
-                trigger_hit_a_lick_mode(game);
+                trigger_hit_a_lick_mode(game, rules);
             }
         }
     }
@@ -377,7 +589,8 @@ pub fn execute_dethrone(
     game: &mut GameState,
     current_time: u64,
     new_payment: u64,
-    old_king_was_vip: bool
+    old_king_was_vip: bool,
+    rules: &Ruleset
 ) -> (u64, u64) {
     // Calculate hold time
     let hold_time = current_time.saturating_sub(game.king_since);
@@ -388,7 +601,7 @@ pub fn execute_dethrone(
         // This correctly handles transition case where player paid Run It Up price
         // CRITICAL: Use king_entry_price, not hit_a_lick_price, because the player
         // may have paid the Run It Up price if they triggered the HAL transition
-        let refund = calculate_hit_a_lick_old_king_payout(game.king_entry_price);
+        let refund = calculate_hit_a_lick_old_king_payout(game.king_entry_price, rules);
         (refund, 0)
     } else {
         // Run It Up mode: refund of entry + yield
@@ -402,7 +615,8 @@ pub fn execute_dethrone(
             game.king_entry_price,
             new_payment,
             hold_time,
-            old_king_was_vip
+            old_king_was_vip,
+            rules
         );
         
         // Deduct yield from pool
@@ -414,25 +628,25 @@ pub fn execute_dethrone(
     }
 }
 
-pub fn execute_steal_with_accounting(game: &mut GameState) -> (u64, u64) {
+pub fn execute_steal_with_accounting(game: &mut GameState, rules: &Ruleset) -> (u64, u64) {
     // Store old price before executing steal
     let old_price = game.current_price;
     
     // Calculate what will come in (new price after steal)
     // Note: This is a test function, so we assume normie (not VIP)
     let incoming = if game.is_hit_a_lick_mode {
-        calculate_hit_a_lick_entry_cost(game.hit_a_lick_price, false)
+        calculate_hit_a_lick_entry_cost(game.hit_a_lick_price, false, rules)
     } else {
         // Use checked arithmetic
         game.current_price
-            .checked_mul(RUN_IT_UP_RATE)
+            .checked_mul(rules.run_it_up_rate)
             .and_then(|v| v.checked_div(100))
             .unwrap_or(u64::MAX)
     };
     
     let total_outgoing = if game.current_king.is_some() {
         if game.is_hit_a_lick_mode {
-            calculate_hit_a_lick_old_king_payout(game.hit_a_lick_price)
+            calculate_hit_a_lick_old_king_payout(game.hit_a_lick_price, rules)
         } else {
             // Refund is old_price
             old_price
@@ -442,7 +656,7 @@ pub fn execute_steal_with_accounting(game: &mut GameState) -> (u64, u64) {
     };
     
     // Execute the steal (this updates price and pools)
-    let _ = execute_steal(game);
+    let _ = execute_steal(game, rules);
     
     let contract_delta = incoming.saturating_sub(total_outgoing);
     (total_outgoing, contract_delta)
@@ -452,42 +666,43 @@ pub fn execute_steal_with_accounting(game: &mut GameState) -> (u64, u64) {
 // ROUND END FUNCTIONS
 // ============================================================================
 
-/// Check if this is a mega hit a lick (pot >= 50 SOL)
-pub fn is_mega_hit_a_lick(total_pot: u64) -> bool {
-    total_pot >= MEGA_POT_THRESHOLD
+/// Check if this is a mega hit a lick (pot >= the tier's threshold, 50 SOL by default)
+pub fn is_mega_hit_a_lick(total_pot: u64, tier: &StakeTier) -> bool {
+    total_pot >= tier.mega_pot_threshold
 }
 
 /// Calculate hit a lick end payouts for 3 winners
-/// Returns (winner1, winner2, winner3, dev, beast, rollover)
+/// Returns (winner1, winner2, winner3, dev, beast, rollover, dust)
+/// - dust: whatever the floored shares leave of total_pot (all seven sum to total_pot)
 /// - winner1: 8% normal / 20% mega (1st place - most recent king)
 /// - winner2: 3% normal / 7% mega (2nd place)
 /// - winner3: 1% normal / 3% mega (3rd place)
-pub fn calculate_hit_a_lick_end_payouts(total_pot: u64) -> (u64, u64, u64, u64, u64, u64) {
-    if is_mega_hit_a_lick(total_pot) {
+pub fn calculate_hit_a_lick_end_payouts(total_pot: u64, tier: &StakeTier, rules: &Ruleset) -> (u64, u64, u64, u64, u64, u64, u64) {
+    let (winner1, winner2, winner3, dev, beast, rollover) = if is_mega_hit_a_lick(total_pot, tier) {
         // Mega Hit A Lick: 20/7/3% winners, 3% dev, 1% beast, 66% rollover
         // Use checked arithmetic to prevent overflow
         let winner1 = total_pot
-            .checked_mul(END_WINNER_1_MEGA)
+            .checked_mul(rules.end_winner_1_mega)
             .and_then(|v| v.checked_div(1000))
             .unwrap_or(0);
         let winner2 = total_pot
-            .checked_mul(END_WINNER_2_MEGA)
+            .checked_mul(rules.end_winner_2_mega)
             .and_then(|v| v.checked_div(1000))
             .unwrap_or(0);
         let winner3 = total_pot
-            .checked_mul(END_WINNER_3_MEGA)
+            .checked_mul(rules.end_winner_3_mega)
             .and_then(|v| v.checked_div(1000))
             .unwrap_or(0);
         let dev = total_pot
-            .checked_mul(END_DEV_MEGA)
+            .checked_mul(rules.end_dev_mega)
             .and_then(|v| v.checked_div(1000))
             .unwrap_or(0);
         let beast = total_pot
-            .checked_mul(END_BEAST_MEGA)
+            .checked_mul(rules.end_beast_mega)
             .and_then(|v| v.checked_div(1000))
             .unwrap_or(0);
         let rollover = total_pot
-            .checked_mul(END_ROLLOVER_MEGA)
+            .checked_mul(rules.end_rollover_mega)
             .and_then(|v| v.checked_div(1000))
             .unwrap_or(0);
         (winner1, winner2, winner3, dev, beast, rollover)
@@ -495,43 +710,118 @@ pub fn calculate_hit_a_lick_end_payouts(total_pot: u64) -> (u64, u64, u64, u64,
         // Normal Hit A Lick: 15/6/4% winners, 0% dev, 0% beast, 75% rollover
         // Use checked arithmetic to prevent overflow
         let winner1 = total_pot
-            .checked_mul(END_WINNER_1)
+            .checked_mul(rules.end_winner_1)
             .and_then(|v| v.checked_div(1000))
             .unwrap_or(0);
         let winner2 = total_pot
-            .checked_mul(END_WINNER_2)
+            .checked_mul(rules.end_winner_2)
             .and_then(|v| v.checked_div(1000))
             .unwrap_or(0);
         let winner3 = total_pot
-            .checked_mul(END_WINNER_3)
+            .checked_mul(rules.end_winner_3)
             .and_then(|v| v.checked_div(1000))
             .unwrap_or(0);
         let dev = total_pot
-            .checked_mul(END_DEV)
+            .checked_mul(rules.end_dev)
             .and_then(|v| v.checked_div(1000))
             .unwrap_or(0);
         let beast = total_pot
-            .checked_mul(END_BEAST)
+            .checked_mul(rules.end_beast)
             .and_then(|v| v.checked_div(1000))
             .unwrap_or(0);
         let rollover = total_pot
-            .checked_mul(END_ROLLOVER)
+            .checked_mul(rules.end_rollover)
             .and_then(|v| v.checked_div(1000))
             .unwrap_or(0);
         (winner1, winner2, winner3, dev, beast, rollover)
+    };
+    let dust = total_pot
+        .saturating_sub(winner1)
+        .saturating_sub(winner2)
+        .saturating_sub(winner3)
+        .saturating_sub(dev)
+        .saturating_sub(beast)
+        .saturating_sub(rollover);
+    (winner1, winner2, winner3, dev, beast, rollover, dust)
+}
+
+/// Settle a round on the vault ledger (end_round): the podium becomes claimable, a dead
+/// round's final king gets their entry back plus yield, and the rest of the pot -
+/// rollover, unfilled places, dust and this round's pending jackpot - becomes the next
+/// jackpot. `game` supplies the round; the ledger holds the pools.
+/// A refunded entry was split when it was paid, so the refund is drawn from the pot
+/// (capped at what the pot holds) rather than counted twice.
+pub fn settle_round(
+    game: &StealSnapshot,
+    recent_kings_count: u8,
+    now: i64,
+    rules: &Ruleset,
+    ledger: &mut VaultLedger,
+) -> Result<RoundSettlement> {
+    require!(game.has_king, StealError::NoKing);
+    let total_pot = ledger.jackpot_balance;
+    let mut settlement = RoundSettlement { total_pot, ..RoundSettlement::default() };
+    
+    if game.is_hit_a_lick_mode {
+        // HIT A LICK MODE: up to 3 winners from recent_kings
+        let (w1, w2, w3, dev, beast, rollover, dust) = calculate_hit_a_lick_end_payouts(total_pot, &game.tier, rules);
+        // Dev and beast fees come from entries only - validate_ruleset pins these shares to 0
+        require!(dev == 0 && beast == 0, StealError::InvalidSplit);
+        settlement.mega = is_mega_hit_a_lick(total_pot, &game.tier);
+        settlement.dust = dust;
+        
+        // Only pay winners that exist; unfilled shares roll over
+        let shares = [w1, w2, w3];
+        let mut unclaimed = 0u64;
+        for (place, share) in shares.iter().enumerate() {
+            if (recent_kings_count as usize) > place {
+                settlement.payouts[place] = *share;
+            } else {
+                unclaimed = unclaimed.saturating_add(*share);
+            }
+        }
+        ledger.jackpot_balance = rollover.saturating_add(unclaimed).saturating_add(dust);
+        if recent_kings_count == 0 {
+            // No recent kings: the current king gets their entry back (no yield in war)
+            settlement.payouts[0] = draw_from_pot(ledger, game.king_entry_price);
+        }
+    } else {
+        // DEAD ROUND (no hit a lick reached): FULL refund + yield, pot rolls forward
+        let hold_time = (now - game.king_since) as u64;
+        // Yield on a nominal "new payment" of the current price, with the tracked VIP status
+        let yield_earned = calculate_yield_with_cap(
+            ledger.yield_pool,
+            game.king_entry_price,
+            game.current_price,
+            hold_time,
+            game.king_was_vip,
+            rules,
+        );
+        let refund = draw_from_pot(ledger, game.king_entry_price);
+        settlement.payouts[0] = refund.saturating_add(yield_earned);
+        settlement.yield_paid = yield_earned;
+        ledger.yield_pool = ledger.yield_pool.saturating_sub(yield_earned);
     }
+    
+    let owed = settlement.payouts.iter().fold(0u64, |sum, p| sum.saturating_add(*p));
+    ledger.claims_outstanding = ledger.claims_outstanding.saturating_add(owed);
+    ledger.dust = ledger.dust.saturating_add(settlement.dust);
+    settlement.rollover = ledger.jackpot_balance;
+    ledger.jackpot_balance = ledger.jackpot_balance.saturating_add(ledger.pending_jackpot);
+    ledger.pending_jackpot = 0;
+    Ok(settlement)
 }
 
-pub fn execute_round_end(game: &mut GameState) {
+pub fn execute_round_end(game: &mut GameState, rules: &Ruleset) {
     // Merge pending jackpot into jackpot balance for next round
     game.jackpot_balance = game.jackpot_balance.saturating_add(game.pending_jackpot);
     game.pending_jackpot = 0;
     
     // Reset game state
-    game.current_price = START_PRICE;
+    game.current_price = game.tier.start_price;
     game.hit_a_lick_price = 0;
     game.is_hit_a_lick_mode = false;
-    game.time_remaining = MAX_TIMER;
+    game.time_remaining = rules.max_timer;
     game.hit_a_lick_end_time = 0;
     game.current_king = None;
     game.king_since = 0;
@@ -540,10 +830,620 @@ pub fn execute_round_end(game: &mut GameState) {
     game.recent_kings = [Pubkey::default(); 3];
     game.recent_kings_count = 0;
     game.growth_steals = 0;
-    game.min_growth_steals_for_war = compute_min_growth_steals_for_war(game.jackpot_balance);
+    game.min_growth_steals_for_war = compute_min_growth_steals_for_war(game.jackpot_balance, SOL_DECIMALS);
     game.growth_hard_end_ts = 0;  // Will be set on next round init
 }
 
+// ============================================================================
+// ENTRY CURRENCY
+// ============================================================================
+
+/// Whether `token_vault` is the vault's associated token account for `mint`
+pub fn is_vault_ata(token_vault: &Pubkey, vault: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> bool {
+    *token_vault == get_associated_token_address_with_program_id(vault, mint, token_program)
+}
+
+/// Convert a SOL-denominated ruleset amount (9 decimals, e.g. min_yield) into the base
+/// units of a game's entry currency - identity for SOL games
+pub fn scale_to_decimals(lamports: u64, decimals: u8) -> u64 {
+    let scaled = if decimals >= SOL_DECIMALS {
+        // 10^(decimals - 9) overflows u128 past 47 decimals - saturate like the multiply
+        match 10u128.checked_pow((decimals - SOL_DECIMALS) as u32) {
+            Some(factor) => (lamports as u128).saturating_mul(factor),
+            None if lamports == 0 => 0,
+            None => u128::MAX,
+        }
+    } else {
+        (lamports as u128) / 10u128.pow((SOL_DECIMALS - decimals) as u32)
+    };
+    scaled.min(u64::MAX as u128) as u64
+}
+
+// ============================================================================
+// STEAL PLAN - shared by steal and quote_steal
+// ============================================================================
+
+/// Run It Up price at which Hit A Lick triggers: hit_a_lick_threshold% of the jackpot
+pub fn hit_a_lick_threshold(jackpot_balance: u64, rules: &Ruleset) -> u64 {
+    jackpot_balance
+        .checked_mul(rules.hit_a_lick_threshold)
+        .and_then(|v| v.checked_div(100))
+        .unwrap_or(u64::MAX)
+}
+
+/// What a dethroned king gets back of `king_entry_price` (yield not included)
+/// Run It Up refunds in full; Hit A Lick refunds hit_a_lick_old_king_refund permille
+pub fn calculate_old_king_refund(king_entry_price: u64, is_hit_a_lick_mode: bool, rules: &Ruleset) -> u64 {
+    if is_hit_a_lick_mode {
+        calculate_hit_a_lick_old_king_payout(king_entry_price, rules)
+    } else {
+        king_entry_price
+    }
+}
+
+/// Check the round still accepts a steal from `player` at game time `now`
+pub fn check_steal_open(game: &StealSnapshot, player: Pubkey, now: i64, rules: &Ruleset) -> Result<()> {
+    if game.is_hit_a_lick_mode {
+        // Hit A Lick mode: allow steals even after timer if king hasn't held 3s yet
+        if now >= game.hit_a_lick_end_time {
+            // Timer expired - check if king has held long enough
+            if game.has_king {
+                let held_secs = (now - game.king_since) as u64;
+                if held_secs >= rules.min_hit_a_lick_hold {
+                    // King has held 3s+, round is settleable - must call end_round
+                    return Err(StealError::RoundEnded.into());
+                }
+                // King hasn't held 3s yet - allow steal to continue (king is snipeable)
+            } else {
+                // No king - round ended, must call end_round
+                return Err(StealError::RoundEnded.into());
+            }
+        }
+        // Timer hasn't expired yet, or timer expired but king < 3s hold - allow steal
+        
+        // Rate limiting: check cooldown in Hit A Lick mode
+        if player == game.last_steal_wallet && game.last_steal_time > 0 {
+            let time_since_last_steal = now - game.last_steal_time;
+            if time_since_last_steal < game.cooldown_seconds as i64 {
+                return Err(StealError::RateLimitExceeded.into());
+            }
+        }
+    } else {
+        require!(
+            now < game.round_end_time,
+            StealError::RoundEnded
+        );
+    }
+    Ok(())
+}
+
+/// Price a steal by `player` at game time `now` and split it, without changing any state
+pub fn plan_steal(
+    game: &StealSnapshot,
+    round: u64,
+    player: Pubkey,
+    is_vip: bool,
+    now: i64,
+    rules: &Ruleset,
+) -> Result<StealQuote> {
+    // Calculate entry cost based on mode
+    // IMPORTANT: This is calculated BEFORE any state updates, so it correctly captures
+    // the price the player will pay even if this steal triggers HAL transition
+    let entry_cost = if game.is_hit_a_lick_mode {
+        // Hit A Lick mode: VIP pays no surcharge, normie pays 12% surcharge
+        calculate_hit_a_lick_entry_cost(game.hit_a_lick_price, is_vip, rules)
+    } else {
+        // Run It Up mode: pay new price (current * 1.12) - VIP and Normie pay same price
+        // VIP benefits are in yield, not price
+        game.current_price
+            .checked_mul(rules.run_it_up_rate)
+            .and_then(|v| v.checked_div(100))
+            .unwrap_or(u64::MAX)
+    };
+    
+    // Safety check: entry_cost should never be 0 if player is paying
+    require!(
+        entry_cost > 0,
+        StealError::InvalidAccount
+    );
+    
+    // In Run It Up: new_price = old_price * 1.12 (same for VIP and normie)
+    // In Hit A Lick: new_price = entry_cost (hit_a_lick_price + surcharge, or just hit_a_lick_price for VIP)
+    let old_price = game.current_price;
+    let new_price = entry_cost;
+    
+    let mut quote = StealQuote {
+        round,
+        hit_a_lick_mode: game.is_hit_a_lick_mode,
+        is_vip,
+        entry_cost,
+        ..StealQuote::default()
+    };
+    
+    // The dethroned king is always the recorded one
+    if game.has_king && game.current_king != player {
+        let hold_time = (now - game.king_since) as u64;
+        
+        // Calculate refund and yield based on mode
+        // Run It Up: FULL REFUND of what they paid; Hit A Lick: 90% of what they paid
+        // This correctly handles transition case where player paid Run It Up price
+        // CRITICAL: king_entry_price contains what the player actually paid, which may be
+        // the Run It Up price if they triggered the HAL transition
+        let refund = calculate_old_king_refund(game.king_entry_price, game.is_hit_a_lick_mode, rules);
+        if game.is_hit_a_lick_mode {
+            // Safety check: refund should never be 0 if player paid something (king_entry_price > 0)
+            // If king_entry_price is 0, that's also an error (player should have paid something)
+            require!(
+                refund > 0 && game.king_entry_price > 0,
+                StealError::InvalidAccount
+            );
+        }
+        
+        // Calculate yield (run it up mode only, hit a lick mode has no yield)
+        let yield_payout = if game.is_hit_a_lick_mode {
+            0
+        } else {
+            // Use tracked VIP status for yield calculation
+            let uncapped_yield = calculate_yield_with_cap(
+                game.yield_pool,
+                game.king_entry_price,
+                new_price,
+                hold_time,
+                game.king_was_vip,
+                rules
+            );
+            // min_yield is SOL-denominated - scale it to the entry currency
+            let min_yield = scale_to_decimals(rules.min_yield, game.currency_decimals);
+            uncapped_yield.max(min_yield.min(game.yield_pool))
+        };
+        
+        quote.old_king = game.current_king;
+        quote.old_king_refund = refund;
+        quote.old_king_yield = yield_payout;
+        quote.old_king_reign = hold_time;
+    }
+    
+    // Overhead payments (dev/beast cuts), yield_add and jackpot_add for pool updates, plus the
+    // rounding dust - together with `refund_share` they sum to entry_cost exactly
+    let (refund_share, dev_cut, beast_cut, yield_add, mut jackpot_add, mut dust) = if game.is_hit_a_lick_mode {
+        let (d, b, y, j, dust) = calculate_hit_a_lick_overhead_split(game.hit_a_lick_price, rules);
+        // Add surcharge to jackpot (entry_cost - hit_a_lick_price for normies, 0 for VIPs)
+        let surcharge = entry_cost.saturating_sub(game.hit_a_lick_price);
+        let share = calculate_hit_a_lick_old_king_payout(game.hit_a_lick_price, rules);
+        (share, d, b, y, j.saturating_add(surcharge), dust)
+    } else {
+        // Calculate taxes on profit_delta (new_price - old_price)
+        calculate_run_it_up_split(old_price, new_price, rules)
+    };
+    
+    // The refund actually credited follows king_entry_price, which can differ from the
+    // share the split reserved - book the difference so every lamport has one bucket
+    let credited = quote.old_king_refund;
+    if quote.old_king == Pubkey::default() {
+        // No king to refund: the reserved share goes to the pot
+        jackpot_add = jackpot_add.saturating_add(refund_share);
+    } else if credited <= refund_share {
+        dust = dust.saturating_add(refund_share - credited);
+    } else {
+        // King paid more than this entry reserves (normie surcharge, or the Run It Up
+        // price that triggered the war) - this entry's jackpot share covers the excess
+        // first, the rest is drawn from the pot
+        let excess = credited - refund_share;
+        let from_entry = excess.min(jackpot_add);
+        jackpot_add -= from_entry;
+        quote.refund_from_pot = excess - from_entry;
+    }
+    quote.dev_cut = dev_cut;
+    quote.beast_cut = beast_cut;
+    quote.yield_pool_add = yield_add;
+    quote.jackpot_add = jackpot_add;
+    quote.dust = dust;
+    
+    if game.is_hit_a_lick_mode {
+        // Price stays frozen at hit_a_lick_price
+        quote.next_price = game.hit_a_lick_price;
+    } else {
+        // Run It Up mode: price increases 12% - the new price is what this player paid
+        quote.next_price = new_price;
+        
+        // Hit A Lick triggers when the NEW price reaches 60% of the jackpot OR this steal
+        // reaches the round's minimum growth steals
+        quote.steals_trigger =
+            game.growth_steals.saturating_add(1) >= game.min_growth_steals_for_war;
+        quote.price_trigger = game.jackpot_balance > 0
+            && quote.next_price >= hit_a_lick_threshold(game.jackpot_balance, rules);
+        quote.triggers_hit_a_lick = quote.price_trigger || quote.steals_trigger;
+        if quote.triggers_hit_a_lick {
+            // Calculate hit a lick price: 3% of pot, clamped to the tier
+            quote.hit_a_lick_price = calculate_hit_a_lick_price(game.jackpot_balance, &game.tier, rules);
+        }
+    }
+    
+    Ok(quote)
+}
+
+/// Take up to `amount` out of the pot, pending jackpot first - for refunds no entry
+/// covers. Returns the amount drawn (less than `amount` only if the pot runs dry)
+pub fn draw_from_pot(ledger: &mut VaultLedger, amount: u64) -> u64 {
+    let from_pending = amount.min(ledger.pending_jackpot);
+    let from_jackpot = (amount - from_pending).min(ledger.jackpot_balance);
+    ledger.pending_jackpot -= from_pending;
+    ledger.jackpot_balance -= from_jackpot;
+    from_pending + from_jackpot
+}
+
+/// Book an executed steal on the vault ledger: the old king's refund and yield become
+/// a claim, the split's pool adds and dust go to the pools, and any refund the entry
+/// doesn't cover is drawn from the pot. The vault itself gains entry_cost - dev_cut - beast_cut
+pub fn book_steal(ledger: &mut VaultLedger, quote: &StealQuote) -> Result<()> {
+    let owed = quote.old_king_refund
+        .checked_add(quote.old_king_yield)
+        .ok_or(StealError::Overflow)?;
+    ledger.claims_outstanding = ledger.claims_outstanding.saturating_add(owed);
+    ledger.yield_pool = ledger.yield_pool
+        .saturating_sub(quote.old_king_yield)
+        .saturating_add(quote.yield_pool_add);
+    // Rounding dust goes to next round's jackpot, so every lamport is booked
+    ledger.pending_jackpot = ledger.pending_jackpot
+        .saturating_add(quote.jackpot_add)
+        .saturating_add(quote.dust);
+    ledger.dust = ledger.dust.saturating_add(quote.dust);
+    require!(
+        draw_from_pot(ledger, quote.refund_from_pot) == quote.refund_from_pot,
+        StealError::InsufficientVaultBalance
+    );
+    Ok(())
+}
+
+/// Book an emergency refund of the sitting king: their full entry becomes a claim,
+/// drawn from the pot (capped at what the pot holds). Returns the amount credited
+pub fn book_emergency_refund(ledger: &mut VaultLedger, king_entry_price: u64) -> u64 {
+    let refund = draw_from_pot(ledger, king_entry_price);
+    ledger.claims_outstanding = ledger.claims_outstanding.saturating_add(refund);
+    refund
+}
+
+// ============================================================================
+// SLIPPAGE PROTECTION
+// ============================================================================
+
+/// Check a steal against the caller's view of the game
+/// `expected_round` / `expected_mode` (true = Hit A Lick) are skipped when None
+pub fn check_steal_slippage(
+    entry_cost: u64,
+    max_cost: u64,
+    round: u64,
+    expected_round: Option<u64>,
+    is_hit_a_lick_mode: bool,
+    expected_mode: Option<bool>,
+) -> Result<()> {
+    if let Some(expected) = expected_round {
+        require!(round == expected, StealError::StaleState);
+    }
+    if let Some(expected) = expected_mode {
+        require!(is_hit_a_lick_mode == expected, StealError::StaleState);
+    }
+    require!(entry_cost <= max_cost, StealError::PriceExceeded);
+    Ok(())
+}
+
+/// Strict mode: fail unless the crown is still held by `expected_king`
+/// (Pubkey::default() = no king). None keeps the availability semantics
+pub fn check_expected_king(current_king: Pubkey, expected_king: Option<Pubkey>) -> Result<()> {
+    if let Some(expected) = expected_king {
+        require!(current_king == expected, StealError::KingChanged);
+    }
+    Ok(())
+}
+
+// ============================================================================
+// SEASONS
+// ============================================================================
+
+/// Check a new season's window: must end after it starts, and not start before the
+/// previous season ended (prev_end_ts = 0 for the first season)
+pub fn validate_season_window(start_ts: i64, end_ts: i64, prev_end_ts: i64) -> Result<()> {
+    require!(end_ts > start_ts, StealError::InvalidSeason);
+    require!(start_ts >= prev_end_ts, StealError::InvalidSeason);
+    Ok(())
+}
+
+/// Whether `now` falls inside the season window [start_ts, end_ts)
+pub fn in_season_window(now: i64, start_ts: i64, end_ts: i64) -> bool {
+    now >= start_ts && now < end_ts
+}
+
+/// Season a round whose first steal lands at `now` belongs to: the game's current season
+/// while its window is open (`window` = None once the season is closed), otherwise 0 -
+/// rounds between seasons play normally, just without leaderboard updates
+pub fn round_season_at(now: i64, season_number: u64, window: Option<(i64, i64)>) -> u64 {
+    match window {
+        Some((start_ts, end_ts)) if in_season_window(now, start_ts, end_ts) => season_number,
+        _ => 0,
+    }
+}
+
+/// A season can only close once the round it last counted is settled, so the prize is
+/// paid from a final board (`total_steals` is reset by end_round and reset_round)
+pub fn season_round_settled(season_number: u64, round_season: u64, total_steals: u64) -> bool {
+    round_season != season_number || total_steals == 0
+}
+
+// ============================================================================
+// LEADERBOARDS
+// ============================================================================
+
+/// How a wallet's board value changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardUpdate {
+    /// Add to the running total (net winnings, steals, wars won)
+    Add(i64),
+    /// Keep the best value seen (longest reign)
+    Max(i64),
+}
+
+/// Apply `update` for `wallet` to a top-N board, keeping it sorted highest first
+/// Ranked wallets are updated exactly. An unranked wallet enters with this update's
+/// value alone if it is positive and beats the lowest entry (evicting it), so totals
+/// near the bottom of a full board are approximate.
+pub fn update_leaderboard(board: &mut [LeaderboardEntry], wallet: Pubkey, update: BoardUpdate) {
+    if wallet == Pubkey::default() || board.is_empty() {
+        return;
+    }
+
+    if let Some(entry) = board.iter_mut().find(|e| e.wallet == wallet) {
+        entry.value = match update {
+            BoardUpdate::Add(delta) => entry.value.saturating_add(delta),
+            BoardUpdate::Max(value) => entry.value.max(value),
+        };
+    } else {
+        let value = match update {
+            BoardUpdate::Add(v) | BoardUpdate::Max(v) => v,
+        };
+        if value <= 0 {
+            return;
+        }
+        // Prefer an empty slot, otherwise the lowest ranked entry
+        let slot = match board.iter().position(|e| e.wallet == Pubkey::default()) {
+            Some(i) => i,
+            None => {
+                let (i, lowest) = board
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, e)| e.value)
+                    .unwrap();
+                if value <= lowest.value {
+                    return;
+                }
+                i
+            }
+        };
+        board[slot] = LeaderboardEntry { wallet, value };
+    }
+
+    // Empty slots last, then highest value first
+    board.sort_by(|a, b| {
+        (a.wallet == Pubkey::default())
+            .cmp(&(b.wallet == Pubkey::default()))
+            .then(b.value.cmp(&a.value))
+    });
+}
+
+/// Net winnings of a reign once it resolves (dethroned or settled): everything the king
+/// was paid out for it less the king_entry_price they paid to take the crown
+/// A break-even king nets zero, a king refunded less than their entry goes negative
+pub fn reign_net_winnings(payout: u64, king_entry_price: u64) -> i64 {
+    (payout as i128 - king_entry_price as i128).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// Split a closed season's prize pool over the top net winners of its leaderboard
+/// Places get SEASON_PRIZE_SHARES% of the pool; a place needs a wallet with positive net
+/// winnings. Returns (winners, amounts, remainder) - the remainder (empty places and
+/// rounding dust) goes back to the config authority.
+pub fn split_season_prize(
+    pool: u64,
+    net_winnings: &[LeaderboardEntry],
+) -> ([Pubkey; 3], [u64; 3], u64) {
+    let mut winners = [Pubkey::default(); 3];
+    let mut amounts = [0u64; 3];
+    let ranked = net_winnings
+        .iter()
+        .filter(|e| e.wallet != Pubkey::default() && e.value > 0);
+    for (place, entry) in ranked.take(3).enumerate() {
+        winners[place] = entry.wallet;
+        amounts[place] = (pool as u128 * SEASON_PRIZE_SHARES[place] as u128 / 100) as u64;
+    }
+    let remainder = pool - amounts.iter().sum::<u64>();
+    (winners, amounts, remainder)
+}
+
+// ============================================================================
+// PLAYER STATS
+// ============================================================================
+
+/// Streak after a steal in `round` of `game`: +1 for the round after the last one
+/// played, unchanged within the same round, otherwise a fresh streak of 1
+/// `last` is the (game, round) of the previous steal, None for a new player
+pub fn advance_streak(streak: u64, last: Option<(Pubkey, u64)>, game: Pubkey, round: u64) -> u64 {
+    match last {
+        Some((g, r)) if g == game && r == round => streak.max(1),
+        Some((g, r)) if g == game && r.checked_add(1) == Some(round) => streak.saturating_add(1),
+        _ => 1,
+    }
+}
+
+// ============================================================================
+// ROUND PRIZES
+// ============================================================================
+
+/// Unclaimed podium share of `wallet` and the places it covers
+/// (one wallet can hold more than one place)
+pub fn unclaimed_prize(
+    winners: &[Pubkey; 3],
+    amounts: &[u64; 3],
+    claimed: &[bool; 3],
+    wallet: &Pubkey,
+) -> (u64, [bool; 3]) {
+    let mut total = 0u64;
+    let mut places = [false; 3];
+    for i in 0..3 {
+        if winners[i] == *wallet && amounts[i] > 0 && !claimed[i] {
+            total = total.saturating_add(amounts[i]);
+            places[i] = true;
+        }
+    }
+    (total, places)
+}
+
+/// Whether every place with a payout has been claimed
+pub fn prizes_settled(amounts: &[u64; 3], claimed: &[bool; 3]) -> bool {
+    amounts.iter().zip(claimed.iter()).all(|(&amount, &done)| amount == 0 || done)
+}
+
+// ============================================================================
+// ROUND HISTORY
+// ============================================================================
+
+/// WAR_TRIGGER_* flags for the conditions that started a war
+pub fn war_trigger_flags(price_trigger: bool, steals_trigger: bool) -> u8 {
+    let mut flags = 0;
+    if price_trigger {
+        flags |= WAR_TRIGGER_PRICE;
+    }
+    if steals_trigger {
+        flags |= WAR_TRIGGER_STEALS;
+    }
+    flags
+}
+
+/// Append `record` to a round history ring, overwriting the oldest once full
+pub fn push_round_record(records: &mut [RoundRecord], total_recorded: &mut u64, record: RoundRecord) {
+    if records.is_empty() {
+        return;
+    }
+    let slot = (*total_recorded % records.len() as u64) as usize;
+    records[slot] = record;
+    *total_recorded = total_recorded.saturating_add(1);
+}
+
+/// Recorded rounds, newest first
+pub fn recent_round_records(records: &[RoundRecord], total_recorded: u64) -> Vec<RoundRecord> {
+    let len = records.len() as u64;
+    let kept = total_recorded.min(len);
+    (1..=kept)
+        .map(|back| records[((total_recorded - back) % len) as usize])
+        .collect()
+}
+
+// ============================================================================
+// ROUND TELEMETRY
+// ============================================================================
+
+/// Record `wallet` in a round's player bloom filter; true if it wasn't seen before
+/// PLAYER_FILTER_HASHES bits per wallet, taken from a hash of the whole key. A new wallet
+/// whose bits are all already set is missed, so unique_players is approximate: it can
+/// undercount (more often as a round passes a few dozen players) but never overcounts
+pub fn note_player(filter: &mut [u8; PLAYER_FILTER_BYTES], wallet: &Pubkey) -> bool {
+    let digest = anchor_lang::solana_program::hash::hashv(&[b"player", wallet.as_ref()]).to_bytes();
+    let mut seen = true;
+    for pair in digest.chunks_exact(2).take(PLAYER_FILTER_HASHES) {
+        let bit = u16::from_le_bytes([pair[0], pair[1]]) as usize % (PLAYER_FILTER_BYTES * 8);
+        let mask = 1u8 << (bit % 8);
+        if filter[bit / 8] & mask == 0 {
+            seen = false;
+            filter[bit / 8] |= mask;
+        }
+    }
+    !seen
+}
+
+// ============================================================================
+// CIRCUIT BREAKER
+// ============================================================================
+
+/// What a set_pause call does to the game clock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseTransition {
+    /// First flag set: the game clock freezes now
+    Trip,
+    /// Last flag cleared: deadlines shift forward by the seconds spent paused
+    Resume { paused_for: i64 },
+    /// Flags changed without starting or ending the pause
+    Update,
+}
+
+/// Classify a pause flag change at `now` (paused_at = when the current pause began)
+pub fn pause_transition(was_paused: bool, is_paused: bool, paused_at: i64, now: i64) -> PauseTransition {
+    match (was_paused, is_paused) {
+        (false, true) => PauseTransition::Trip,
+        (true, false) => PauseTransition::Resume { paused_for: now.saturating_sub(paused_at).max(0) },
+        _ => PauseTransition::Update,
+    }
+}
+
+/// Current time on the game clock: frozen at paused_at while paused
+pub fn game_clock(is_paused: bool, paused_at: i64, unix_timestamp: i64) -> i64 {
+    if is_paused {
+        paused_at
+    } else {
+        unix_timestamp
+    }
+}
+
+/// Shift a game-clock deadline forward on resume (0 = unset, stays unset)
+pub fn shift_deadline(deadline: i64, paused_for: i64) -> i64 {
+    if deadline > 0 {
+        deadline.saturating_add(paused_for)
+    } else {
+        deadline
+    }
+}
+
+// ============================================================================
+// VAULT SOLVENCY
+// ============================================================================
+
+/// Calculate what the vault owes: jackpot + pending jackpot + yield pool + unclaimed Claim
+/// balances + the vault's own reserve (rent-exempt minimum for SOL vaults, 0 for token vaults)
+/// The sitting king's entry is not a liability - it already paid the previous king and the
+/// cuts, and any refund of it is drawn from the pot
+pub fn calculate_vault_liabilities(
+    jackpot_balance: u64,
+    pending_jackpot: u64,
+    yield_pool: u64,
+    claims_outstanding: u64,
+    reserve: u64,
+) -> Result<u64> {
+    jackpot_balance
+        .checked_add(pending_jackpot)
+        .and_then(|v| v.checked_add(yield_pool))
+        .and_then(|v| v.checked_add(claims_outstanding))
+        .and_then(|v| v.checked_add(reserve))
+        .ok_or_else(|| StealError::Overflow.into())
+}
+
+/// Compare vault lamports against liabilities - returns (surplus, deficit), at most one non-zero
+pub fn calculate_vault_surplus(vault_lamports: u64, liabilities: u64) -> (u64, u64) {
+    (
+        vault_lamports.saturating_sub(liabilities),
+        liabilities.saturating_sub(vault_lamports),
+    )
+}
+
+/// Resolve how much sweep_surplus may move - `None` sweeps the whole surplus
+/// Never more than the surplus, so funds owed to players are untouchable
+pub fn calculate_sweep_amount(
+    vault_lamports: u64,
+    liabilities: u64,
+    requested: Option<u64>,
+) -> Result<u64> {
+    let (surplus, _deficit) = calculate_vault_surplus(vault_lamports, liabilities);
+    let amount = requested.unwrap_or(surplus);
+    require!(amount > 0, StealError::NoSurplus);
+    require!(amount <= surplus, StealError::SweepExceedsSurplus);
+    Ok(amount)
+}
+
 // ============================================================================
 // SAFETY FUNCTIONS
 // ============================================================================
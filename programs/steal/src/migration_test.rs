@@ -0,0 +1,160 @@
+// ============================================================================
+// MIGRATION TESTS - older GameAccount layouts upgrade to the current one
+// ============================================================================
+
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::state::{GameAccount, Ruleset, StakeTier, GAME_LAYOUT_VERSION};
+
+/// Bytes each layout version appended (index = version)
+fn appended_bytes(version: u8) -> usize {
+    match version {
+        2 => 8,                                           // dust
+        3 => 8,                                           // game_id
+        4 => StakeTier::SIZE,                             // tier
+        5 => 32 + 1,                                      // currency_mint, currency_decimals
+        6 => 8 + 8,                                       // season_number, round_season
+        7 => 8,                                           // claims_outstanding
+        8 => 8 + 8 + 1 + 1,                               // round_start_ts .. has_round_history
+        9 => 8 + 8 + 8 + 8 + 32 + 4 + PLAYER_FILTER_BYTES, // round telemetry
+        10 => Ruleset::SIZE + 8,                          // rules, rules_version
+        _ => 0,
+    }
+}
+
+/// Account size as written by `version`
+fn layout_size(version: u8) -> usize {
+    GameAccount::SIZE - (version + 1..=GAME_LAYOUT_VERSION).map(appended_bytes).sum::<usize>()
+}
+
+fn serialize(game: &GameAccount) -> Vec<u8> {
+    let mut data = Vec::new();
+    game.try_serialize(&mut data).unwrap();
+    data
+}
+
+/// A game written by `version`: the current layout cut down to that version's size
+fn stored_as(game: &GameAccount, version: u8) -> Vec<u8> {
+    let mut old = GameAccount { layout_version: version, ..game.clone() };
+    if version < 1 {
+        old._reserved = [0; 7];
+    }
+    let mut data = serialize(&old);
+    data.truncate(layout_size(version));
+    data
+}
+
+/// A running game with every field set away from its default
+fn sample_game() -> GameAccount {
+    let king = Pubkey::new_unique();
+    GameAccount {
+        current_price: 123_000_000,
+        jackpot_balance: 5_000_000_000,
+        pending_jackpot: 7_000,
+        yield_pool: 900_000,
+        round_end_time: 1_700_000_000,
+        current_king: king,
+        king_since: 1_699_999_000,
+        king_entry_price: 123_000_000,
+        has_king: true,
+        dev_wallet: Pubkey::new_unique(),
+        beast_wallet: Pubkey::new_unique(),
+        steal_mint: Pubkey::new_unique(),
+        round: 42,
+        total_steals: 17,
+        recent_kings: [king, Pubkey::default(), Pubkey::default()],
+        recent_kings_count: 1,
+        growth_steals: 9,
+        min_growth_steals_for_war: 25,
+        bump: 254,
+        dust: 11,
+        game_id: 3,
+        tier: StakeTier { start_price: 50_000_000, ..StakeTier::default() },
+        currency_mint: Pubkey::new_unique(),
+        currency_decimals: 6,
+        season_number: 2,
+        round_season: 2,
+        claims_outstanding: 400_000,
+        round_start_ts: 1_699_990_000,
+        peak_price: 150_000_000,
+        war_trigger: 1,
+        has_round_history: true,
+        war_start_ts: 1_699_995_000,
+        war_steals: 4,
+        round_volume: 2_000_000_000,
+        longest_reign_secs: 600,
+        longest_reign_wallet: king,
+        unique_players: 8,
+        player_filter: [0xa5; PLAYER_FILTER_BYTES],
+        rules: Ruleset { max_timer: 7_200, ..Ruleset::default() },
+        rules_version: 5,
+        layout_version: GAME_LAYOUT_VERSION,
+        ..GameAccount::default()
+    }
+}
+
+#[test]
+fn every_layout_version_loads_at_the_current_size() {
+    let game = sample_game();
+    assert_eq!(serialize(&game).len(), GameAccount::SIZE);
+    for version in 0..=GAME_LAYOUT_VERSION {
+        let upgraded = GameAccount::load_versioned(&stored_as(&game, version)).unwrap();
+        assert_eq!(upgraded.layout_version, GAME_LAYOUT_VERSION);
+        // Written back (migrate_game) and read again: nothing changes
+        let data = serialize(&upgraded);
+        assert_eq!(data.len(), GameAccount::SIZE);
+        assert_eq!(serialize(&GameAccount::load_versioned(&data).unwrap()), data);
+    }
+}
+
+#[test]
+fn legacy_layout_upgrades_to_current() {
+    let game = sample_game();
+    let upgraded = GameAccount::load_versioned(&stored_as(&game, 0)).unwrap();
+
+    // Original fields survive
+    assert_eq!(upgraded.current_price, game.current_price);
+    assert_eq!(upgraded.jackpot_balance, game.jackpot_balance);
+    assert_eq!(upgraded.current_king, game.current_king);
+    assert_eq!(upgraded.recent_kings, game.recent_kings);
+    assert_eq!(upgraded.min_growth_steals_for_war, game.min_growth_steals_for_war);
+    assert_eq!(upgraded.bump, game.bump);
+
+    // Appended fields take their migration defaults
+    assert_eq!(upgraded.dust, 0);
+    assert_eq!(upgraded.game_id, 0);
+    assert_eq!(upgraded.tier, StakeTier::default());
+    assert_eq!(upgraded.currency_mint, Pubkey::default());
+    assert_eq!(upgraded.currency_decimals, SOL_DECIMALS);
+    assert_eq!((upgraded.season_number, upgraded.round_season), (0, 0));
+    assert_eq!(upgraded.claims_outstanding, 0);
+    assert!(!upgraded.has_round_history);
+    assert_eq!(upgraded.unique_players, 0);
+    assert_eq!(upgraded.player_filter, [0; PLAYER_FILTER_BYTES]);
+    assert_eq!(upgraded.rules, Ruleset::default());
+    assert_eq!(upgraded.rules_version, 0);
+}
+
+#[test]
+fn previous_layout_upgrades_to_current() {
+    let game = sample_game();
+    let previous = GAME_LAYOUT_VERSION - 1;
+    let upgraded = GameAccount::load_versioned(&stored_as(&game, previous)).unwrap();
+
+    // Everything up to the previous layout survives, telemetry included
+    assert_eq!(upgraded.tier, game.tier);
+    assert_eq!(upgraded.currency_mint, game.currency_mint);
+    assert_eq!(upgraded.currency_decimals, game.currency_decimals);
+    assert_eq!(upgraded.claims_outstanding, game.claims_outstanding);
+    assert_eq!(upgraded.longest_reign_wallet, game.longest_reign_wallet);
+    assert_eq!(upgraded.unique_players, game.unique_players);
+    assert_eq!(upgraded.player_filter, game.player_filter);
+    // Only the ruleset snapshot is new (migrate_game then copies GameParams.active)
+    assert_eq!(upgraded.rules, Ruleset::default());
+    assert_eq!(upgraded.rules_version, 0);
+
+    // A current account is left as it is
+    let current = serialize(&game);
+    assert_eq!(serialize(&GameAccount::load_versioned(&current).unwrap()), current);
+}
@@ -0,0 +1,105 @@
+// ============================================================================
+// GAME PARAMS TESTS - ruleset invariants and ruleset-driven logic
+// ============================================================================
+
+use crate::errors::StealError;
+use crate::logic::*;
+use crate::state::{Ruleset, StakeTier};
+
+#[test]
+fn default_ruleset_is_valid() {
+    assert!(validate_ruleset(&Ruleset::default()).is_ok());
+}
+
+#[test]
+fn surplus_splits_must_sum_to_1000() {
+    let mut rules = Ruleset::default();
+    rules.surplus_jackpot -= 1;
+    assert_eq!(validate_ruleset(&rules).unwrap_err(), StealError::InvalidSplit.into());
+
+    let mut rules = Ruleset::default();
+    rules.surplus_dev += 1;
+    assert_eq!(validate_ruleset(&rules).unwrap_err(), StealError::InvalidSplit.into());
+}
+
+#[test]
+fn end_payouts_may_not_exceed_1000() {
+    // Winners + rollover below 1000 is allowed (remainder is not paid out)
+    let mut rules = Ruleset::default();
+    rules.end_rollover -= 10;
+    assert!(validate_ruleset(&rules).is_ok());
+
+    let mut rules = Ruleset::default();
+    rules.end_rollover += 1;
+    assert_eq!(validate_ruleset(&rules).unwrap_err(), StealError::InvalidSplit.into());
+
+    let mut rules = Ruleset::default();
+    rules.end_winner_1_mega += 1;
+    assert_eq!(validate_ruleset(&rules).unwrap_err(), StealError::InvalidSplit.into());
+}
+
+#[test]
+fn end_dev_and_beast_shares_must_be_zero() {
+    // Moving rollover to dev would strand that slice of the pot - end_round never pays it
+    let mut rules = Ruleset::default();
+    rules.end_rollover -= 10;
+    rules.end_dev += 10;
+    assert_eq!(validate_ruleset(&rules).unwrap_err(), StealError::InvalidSplit.into());
+
+    let mut rules = Ruleset::default();
+    rules.end_rollover_mega -= 10;
+    rules.end_beast_mega += 10;
+    assert_eq!(validate_ruleset(&rules).unwrap_err(), StealError::InvalidSplit.into());
+}
+
+#[test]
+fn hit_a_lick_overhead_may_not_exceed_1000() {
+    let mut rules = Ruleset::default();
+    rules.hit_a_lick_jackpot += 1;
+    assert_eq!(validate_ruleset(&rules).unwrap_err(), StealError::InvalidSplit.into());
+}
+
+#[test]
+fn out_of_range_params_rejected() {
+    let rules = Ruleset { yield_time_bonus_cap_secs: 0, ..Ruleset::default() };
+    assert_eq!(validate_ruleset(&rules).unwrap_err(), StealError::InvalidParams.into());
+
+    let rules = Ruleset { run_it_up_rate: 100, ..Ruleset::default() };
+    assert_eq!(validate_ruleset(&rules).unwrap_err(), StealError::InvalidParams.into());
+
+    let rules = Ruleset { timer_add: Ruleset::default().max_timer + 1, ..Ruleset::default() };
+    assert_eq!(validate_ruleset(&rules).unwrap_err(), StealError::InvalidParams.into());
+
+    // Emergency refund must remain reachable while paused
+    let rules = Ruleset { emergency_refund_grace_secs: 0, ..Ruleset::default() };
+    assert_eq!(validate_ruleset(&rules).unwrap_err(), StealError::InvalidParams.into());
+}
+
+#[test]
+fn logic_reads_ruleset_instead_of_constants() {
+    let mut rules = Ruleset {
+        hit_a_lick_pct_of_pot: 100, // 10% of pot
+        ..Ruleset::default()
+    };
+    // 10 SOL pot -> 1 SOL price (would be 0.3 SOL under the default 3%)
+    assert_eq!(calculate_hit_a_lick_price(10_000_000_000, &StakeTier::default(), &rules), 1_000_000_000);
+
+    rules.surplus_dev = 100;
+    rules.surplus_jackpot = 695;
+    let (refund, dev, _, _, jackpot, _) = calculate_run_it_up_split(1_000, 2_000, &rules);
+    assert_eq!(refund, 1_000);
+    assert_eq!(dev, 100);
+    assert_eq!(jackpot, 695);
+}
+
+#[test]
+fn game_simulation_uses_ruleset_timers() {
+    let rules = Ruleset { max_timer: 120, timer_add: 60, ..Ruleset::default() };
+    let mut game = new_game(&StakeTier::default(), &rules);
+    assert_eq!(game.time_remaining, 120);
+
+    execute_steal(&mut game, &rules).unwrap();
+    // Timer is capped at the ruleset's max_timer
+    assert_eq!(game.time_remaining, 120);
+    assert_eq!(game.current_price, crate::constants::START_PRICE * rules.run_it_up_rate / 100);
+}
@@ -0,0 +1,27 @@
+// ============================================================================
+// PLAYER STATS TESTS - lifetime round streaks
+// ============================================================================
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::logic::*;
+
+#[test]
+fn streak_counts_consecutive_rounds_of_one_game() {
+    let game = Pubkey::new_unique();
+
+    // First steal ever
+    assert_eq!(advance_streak(0, None, game, 7), 1);
+    // Same round again: unchanged
+    assert_eq!(advance_streak(1, Some((game, 7)), game, 7), 1);
+    // Next round: extended
+    assert_eq!(advance_streak(1, Some((game, 7)), game, 8), 2);
+    // Skipped a round: restarts
+    assert_eq!(advance_streak(2, Some((game, 8)), game, 10), 1);
+}
+
+#[test]
+fn streak_restarts_on_another_game() {
+    let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    assert_eq!(advance_streak(5, Some((a, 3)), b, 4), 1);
+}
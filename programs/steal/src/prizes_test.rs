@@ -0,0 +1,29 @@
+// ============================================================================
+// ROUND PRIZE TESTS - podium entitlements recorded at settlement
+// ============================================================================
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::logic::*;
+
+#[test]
+fn each_winner_claims_only_their_places() {
+    let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let winners = [a, b, a];
+    let amounts = [500, 300, 200];
+
+    // A wallet holding two places claims both at once
+    assert_eq!(unclaimed_prize(&winners, &amounts, &[false; 3], &a), (700, [true, false, true]));
+    assert_eq!(unclaimed_prize(&winners, &amounts, &[true, false, true], &a), (0, [false; 3]));
+    assert_eq!(unclaimed_prize(&winners, &amounts, &[false; 3], &Pubkey::new_unique()).0, 0);
+}
+
+#[test]
+fn empty_places_never_block_settlement() {
+    let amounts = [500, 0, 0];
+    assert!(!prizes_settled(&amounts, &[false; 3]));
+    assert!(prizes_settled(&amounts, &[true, false, false]));
+    // Default-key slots have no payout and can't be claimed
+    let winners = [Pubkey::new_unique(), Pubkey::default(), Pubkey::default()];
+    assert_eq!(unclaimed_prize(&winners, &amounts, &[false; 3], &Pubkey::default()).0, 0);
+}
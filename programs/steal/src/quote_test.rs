@@ -0,0 +1,94 @@
+// ============================================================================
+// QUOTE TESTS - the steal plan quote_steal returns is the one steal executes
+// ============================================================================
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::constants::*;
+use crate::logic::*;
+use crate::state::{Ruleset, StealSnapshot};
+
+fn reigning_game(king: Pubkey) -> StealSnapshot {
+    StealSnapshot {
+        current_price: START_PRICE,
+        has_king: true,
+        current_king: king,
+        king_since: 1_000,
+        king_entry_price: START_PRICE,
+        round_end_time: 100_000,
+        min_growth_steals_for_war: 50,
+        currency_decimals: 9,
+        ..StealSnapshot::default()
+    }
+}
+
+#[test]
+fn run_it_up_quote_accounts_for_entry_cost() {
+    let rules = Ruleset::default();
+    let king = Pubkey::new_unique();
+    let game = reigning_game(king);
+
+    let quote = plan_steal(&game, 7, Pubkey::new_unique(), false, 1_060, &rules).unwrap();
+    assert_eq!(quote.round, 7);
+    assert_eq!(quote.entry_cost, START_PRICE * rules.run_it_up_rate / 100);
+    assert_eq!(quote.next_price, quote.entry_cost);
+    assert_eq!(quote.old_king, king);
+    assert_eq!(quote.old_king_refund, START_PRICE);
+    assert_eq!(quote.old_king_reign, 60);
+    // Every lamport of the entry is either refunded or split
+    assert_eq!(
+        quote.old_king_refund + quote.dev_cut + quote.beast_cut
+            + quote.yield_pool_add + quote.jackpot_add + quote.dust,
+        quote.entry_cost
+    );
+    assert!(!quote.triggers_hit_a_lick);
+}
+
+#[test]
+fn king_restealing_gets_no_payout() {
+    let rules = Ruleset::default();
+    let king = Pubkey::new_unique();
+    let quote = plan_steal(&reigning_game(king), 1, king, false, 1_060, &rules).unwrap();
+    assert_eq!(quote.old_king, Pubkey::default());
+    assert_eq!(quote.old_king_refund + quote.old_king_yield, 0);
+}
+
+#[test]
+fn quote_flags_hit_a_lick_trigger() {
+    let rules = Ruleset::default();
+    let mut game = reigning_game(Pubkey::new_unique());
+    game.growth_steals = 49;
+    game.jackpot_balance = 10_000_000_000;
+
+    let quote = plan_steal(&game, 1, Pubkey::new_unique(), false, 1_060, &rules).unwrap();
+    assert!(quote.triggers_hit_a_lick && quote.steals_trigger && !quote.price_trigger);
+    assert_eq!(
+        quote.hit_a_lick_price,
+        calculate_hit_a_lick_price(game.jackpot_balance, &game.tier, &rules)
+    );
+}
+
+#[test]
+fn hit_a_lick_quote_charges_normie_surcharge() {
+    let rules = Ruleset::default();
+    let mut game = reigning_game(Pubkey::new_unique());
+    game.is_hit_a_lick_mode = true;
+    game.hit_a_lick_price = 300_000_000;
+    game.hit_a_lick_end_time = 100_000;
+
+    let vip = plan_steal(&game, 1, Pubkey::new_unique(), true, 1_060, &rules).unwrap();
+    let normie = plan_steal(&game, 1, Pubkey::new_unique(), false, 1_060, &rules).unwrap();
+    assert_eq!(vip.entry_cost, game.hit_a_lick_price);
+    assert!(normie.entry_cost > vip.entry_cost);
+    assert_eq!(normie.jackpot_add - vip.jackpot_add, normie.entry_cost - vip.entry_cost);
+    // Price stays frozen during the war
+    assert_eq!(normie.next_price, game.hit_a_lick_price);
+}
+
+#[test]
+fn closed_round_rejects_quote_check() {
+    let rules = Ruleset::default();
+    let game = reigning_game(Pubkey::new_unique());
+    assert!(check_steal_open(&game, Pubkey::new_unique(), 1_060, &rules).is_ok());
+    assert!(check_steal_open(&game, Pubkey::new_unique(), 100_000, &rules).is_err());
+}
@@ -0,0 +1,81 @@
+// ============================================================================
+// ROUND HISTORY TESTS - the ring keeps the newest ROUND_HISTORY_SIZE rounds
+// ============================================================================
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::constants::*;
+use crate::logic::*;
+use crate::state::RoundRecord;
+
+fn record(round: u64) -> RoundRecord {
+    RoundRecord { round, ..RoundRecord::default() }
+}
+
+#[test]
+fn records_are_returned_newest_first() {
+    let mut records = [RoundRecord::default(); ROUND_HISTORY_SIZE];
+    let mut total = 0;
+    assert!(recent_round_records(&records, total).is_empty());
+
+    for round in 1..=3 {
+        push_round_record(&mut records, &mut total, record(round));
+    }
+    let rounds: Vec<u64> = recent_round_records(&records, total).iter().map(|r| r.round).collect();
+    assert_eq!(rounds, vec![3, 2, 1]);
+}
+
+#[test]
+fn full_ring_overwrites_oldest() {
+    let mut records = [RoundRecord::default(); ROUND_HISTORY_SIZE];
+    let mut total = 0;
+    let rounds = ROUND_HISTORY_SIZE as u64 + 5;
+    for round in 1..=rounds {
+        push_round_record(&mut records, &mut total, record(round));
+    }
+
+    assert_eq!(total, rounds);
+    let recent = recent_round_records(&records, total);
+    assert_eq!(recent.len(), ROUND_HISTORY_SIZE);
+    assert_eq!(recent[0].round, rounds);
+    assert_eq!(recent[ROUND_HISTORY_SIZE - 1].round, 6);
+}
+
+#[test]
+fn war_trigger_flags_combine() {
+    assert_eq!(war_trigger_flags(false, false), 0);
+    assert_eq!(war_trigger_flags(true, false), WAR_TRIGGER_PRICE);
+    assert_eq!(war_trigger_flags(true, true), WAR_TRIGGER_PRICE | WAR_TRIGGER_STEALS);
+}
+
+#[test]
+fn record_size_matches_layout() {
+    assert_eq!(std::mem::size_of::<RoundRecord>(), RoundRecord::SIZE);
+}
+
+#[test]
+fn note_player_counts_each_wallet_once() {
+    let mut filter = [0u8; PLAYER_FILTER_BYTES];
+    let alice = Pubkey::new_from_array([1; 32]);
+    let bob = Pubkey::new_from_array([2; 32]);
+    assert!(note_player(&mut filter, &alice));
+    assert!(!note_player(&mut filter, &alice));
+    assert!(note_player(&mut filter, &bob));
+    // A full filter has seen everyone - undercount, never over
+    let mut full = [0xff; PLAYER_FILTER_BYTES];
+    assert!(!note_player(&mut full, &Pubkey::new_from_array([3; 32])));
+}
+
+#[test]
+fn note_player_hashes_the_whole_key() {
+    // Wallets sharing all but their last byte are still told apart
+    let mut filter = [0u8; PLAYER_FILTER_BYTES];
+    let counted = (0..40u8)
+        .filter(|&i| {
+            let mut key = [7u8; 32];
+            key[31] = i;
+            note_player(&mut filter, &Pubkey::new_from_array(key))
+        })
+        .count();
+    assert!(counted >= 36, "counted {} of 40", counted);
+}
@@ -0,0 +1,80 @@
+// ============================================================================
+// SEASON TESTS - scheduled steal windows
+// ============================================================================
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::constants::LEADERBOARD_SIZE;
+use crate::logic::*;
+use crate::state::LeaderboardEntry;
+
+#[test]
+fn season_window_is_half_open() {
+    assert!(!in_season_window(99, 100, 200));
+    assert!(in_season_window(100, 100, 200));
+    assert!(in_season_window(199, 100, 200));
+    assert!(!in_season_window(200, 100, 200));
+}
+
+#[test]
+fn rounds_between_seasons_play_without_a_season() {
+    let window = Some((100, 200));
+    assert_eq!(round_season_at(150, 3, window), 3);
+    // Before the window opens, after it ends, or once the season is closed: no season
+    assert_eq!(round_season_at(99, 3, window), 0);
+    assert_eq!(round_season_at(200, 3, window), 0);
+    assert_eq!(round_season_at(150, 3, None), 0);
+}
+
+#[test]
+fn season_closes_only_after_its_last_round_settles() {
+    // Round of this season still running
+    assert!(!season_round_settled(3, 3, 7));
+    // Settled (end_round / reset_round clear total_steals)
+    assert!(season_round_settled(3, 3, 0));
+    // A round outside the season doesn't hold it open
+    assert!(season_round_settled(3, 0, 7));
+}
+
+#[test]
+fn seasons_cannot_be_empty_or_overlap() {
+    assert!(validate_season_window(100, 200, 0).is_ok());
+    assert!(validate_season_window(200, 200, 0).is_err());
+    // Next season may start exactly when the previous one ended, not before
+    assert!(validate_season_window(200, 300, 200).is_ok());
+    assert!(validate_season_window(150, 300, 200).is_err());
+}
+
+#[test]
+fn season_prize_pays_the_top_net_winners() {
+    let mut board = [LeaderboardEntry::default(); LEADERBOARD_SIZE];
+    let wallets: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+    for (i, w) in wallets.iter().enumerate() {
+        update_leaderboard(&mut board, *w, BoardUpdate::Add(100 - i as i64));
+    }
+
+    let pool = 1_000_000_001;
+    let (winners, amounts, remainder) = split_season_prize(pool, &board);
+    assert_eq!(winners, [wallets[0], wallets[1], wallets[2]]);
+    assert_eq!(amounts, [500_000_000, 300_000_000, 200_000_000]);
+    // Rounding dust returns to the authority, nothing is stranded
+    assert_eq!(remainder, 1);
+    assert_eq!(amounts.iter().sum::<u64>() + remainder, pool);
+}
+
+#[test]
+fn season_prize_without_winners_returns_to_authority() {
+    let mut board = [LeaderboardEntry::default(); LEADERBOARD_SIZE];
+    let (winner, loser) = (Pubkey::new_unique(), Pubkey::new_unique());
+    update_leaderboard(&mut board, winner, BoardUpdate::Add(5));
+    update_leaderboard(&mut board, loser, BoardUpdate::Add(-5));
+
+    // Only one wallet finished ahead: 2nd and 3rd place shares go back
+    let (winners, amounts, remainder) = split_season_prize(1_000, &board);
+    assert_eq!(winners, [winner, Pubkey::default(), Pubkey::default()]);
+    assert_eq!(amounts, [500, 0, 0]);
+    assert_eq!(remainder, 500);
+
+    let empty = [LeaderboardEntry::default(); LEADERBOARD_SIZE];
+    assert_eq!(split_season_prize(1_000, &empty).2, 1_000);
+}
@@ -0,0 +1,32 @@
+// ============================================================================
+// SLIPPAGE TESTS - steal never charges more than the caller signed for
+// ============================================================================
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::logic::*;
+
+#[test]
+fn max_cost_is_inclusive() {
+    assert!(check_steal_slippage(1_000, 1_000, 3, None, false, None).is_ok());
+    assert!(check_steal_slippage(1_001, 1_000, 3, None, false, None).is_err());
+}
+
+#[test]
+fn stale_round_or_mode_is_rejected() {
+    assert!(check_steal_slippage(1_000, 2_000, 3, Some(3), true, Some(true)).is_ok());
+    // Round settled while the transaction was in flight
+    assert!(check_steal_slippage(1_000, 2_000, 4, Some(3), false, None).is_err());
+    // Game flipped into Hit A Lick mode
+    assert!(check_steal_slippage(1_000, 2_000, 3, None, true, Some(false)).is_err());
+}
+
+#[test]
+fn expected_king_is_opt_in() {
+    let (king, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+    assert!(check_expected_king(king, None).is_ok());
+    assert!(check_expected_king(king, Some(king)).is_ok());
+    assert!(check_expected_king(king, Some(other)).is_err());
+    // No king yet
+    assert!(check_expected_king(Pubkey::default(), Some(Pubkey::default())).is_ok());
+}
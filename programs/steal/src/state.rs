@@ -2,11 +2,39 @@
 // STEAL STATE - A+ RULESET
 // ============================================================================
 
-#[cfg(any(not(test), feature = "idl-build"))]
 use anchor_lang::prelude::*;
 
+use crate::constants::*;
+
+/// Current GameAccount layout version (see GameAccount::upgrade_layout)
+/// - 0: legacy layout (layout_version byte was the first byte of `_padding`)
+/// - 1: layout_version stamped in place of `_padding`
+/// - 2: appended `dust`
+/// - 3: appended `game_id`
+/// - 4: appended `tier`
+/// - 5: appended `currency_mint`, `currency_decimals`
+/// - 6: appended `season_number`, `round_season`
+/// - 7: appended `claims_outstanding`
+/// - 8: appended `round_start_ts`, `peak_price`, `war_trigger`, `has_round_history`
+/// - 9: appended `war_start_ts`, `war_steals`, `round_volume`, `longest_reign_secs`,
+///   `longest_reign_wallet`, `unique_players`, `player_filter`
+/// - 10: appended `rules`, `rules_version`
+pub const GAME_LAYOUT_VERSION: u8 = 10;
+
+/// PDA seed suffix for a game instance's `game` and `vault` PDAs
+/// Game 0 uses an empty suffix - seeds are concatenated before hashing, so it keeps the
+/// original `[b"game"]` / `[b"vault"]` addresses; other ids use their little-endian bytes
+pub fn game_id_seed(game_id: u64) -> Vec<u8> {
+    if game_id == 0 {
+        Vec::new()
+    } else {
+        game_id.to_le_bytes().to_vec()
+    }
+}
+
 /// On-chain game account stored as PDA
-#[cfg(any(not(test), feature = "idl-build"))]
+/// New fields are only ever appended, and bump GAME_LAYOUT_VERSION; older accounts
+/// are grown in place by `migrate_game`
 #[account]
 #[derive(Default)]
 pub struct GameAccount {
@@ -26,8 +54,10 @@ pub struct GameAccount {
     pub hit_a_lick_end_time: i64,
     /// Whether game is in hit a lick mode
     pub is_hit_a_lick_mode: bool,
-    /// Padding for alignment (matches on-chain account layout)
-    pub _padding: u64,
+    /// Layout version of this account (occupies the first byte of the legacy `_padding: u64`)
+    pub layout_version: u8,
+    /// Remaining bytes of the legacy `_padding: u64` (keeps later field offsets unchanged)
+    pub _reserved: [u8; 7],
     /// Current king's wallet (None if no king yet)
     pub current_king: Pubkey,
     /// Timestamp when current king took the crown
@@ -72,9 +102,51 @@ pub struct GameAccount {
     pub king_was_vip: bool,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Lifetime rounding remainders from permille splits, routed to pending_jackpot (v2)
+    pub dust: u64,
+    /// Instance id - seeds the game and vault PDAs (see game_id_seed) (v3)
+    pub game_id: u64,
+    /// Stakes for this table: starting price, Hit A Lick clamps, mega pot (v4)
+    pub tier: StakeTier,
+    /// Entry currency mint - Pubkey::default() for SOL games (v5)
+    pub currency_mint: Pubkey,
+    /// Decimals of the entry currency (9 for SOL) (v5)
+    pub currency_decimals: u8,
+    /// Latest season started for this game (0 = no seasons, steals are unrestricted) (v6)
+    pub season_number: u64,
+    /// Season the current round belongs to (set by its first steal, 0 = none) (v6)
+    pub round_season: u64,
+    /// Credited to Claim accounts and not yet withdrawn - still held by the vault (v7)
+    pub claims_outstanding: u64,
+    /// Timestamp the current round started (0 = unknown, round predates v8) (v8)
+    pub round_start_ts: i64,
+    /// Highest entry cost paid this round (v8)
+    pub peak_price: u64,
+    /// Why the current round went to war - WAR_TRIGGER_* flags, 0 while growing (v8)
+    pub war_trigger: u8,
+    /// Whether a RoundHistory exists - settlements must then append to it (v8)
+    pub has_round_history: bool,
+    /// Timestamp the current round entered Hit A Lick mode (0 = still growing) (v9)
+    pub war_start_ts: i64,
+    /// Steals made in Hit A Lick mode this round (v9)
+    pub war_steals: u64,
+    /// Total entry costs paid this round, in the game's entry currency (v9)
+    pub round_volume: u64,
+    /// Longest completed reign this round, in seconds (v9)
+    pub longest_reign_secs: u64,
+    /// King who held that reign (default if none yet) (v9)
+    pub longest_reign_wallet: Pubkey,
+    /// Distinct stealers this round - approximate, may undercount (see logic::note_player) (v9)
+    pub unique_players: u32,
+    /// Bloom filter of this round's stealers behind unique_players (v9)
+    pub player_filter: [u8; PLAYER_FILTER_BYTES],
+    /// Ruleset this table plays the current round with - GameParams.active as of the
+    /// round's start, so another table's round boundary can't change it mid-round (v10)
+    pub rules: Ruleset,
+    /// GameParams.version of `rules` (0 = not snapshotted yet, until migrate_game) (v10)
+    pub rules_version: u64,
 }
 
-#[cfg(any(not(test), feature = "idl-build"))]
 impl GameAccount {
     /// Size of the account in bytes
     pub const SIZE: usize = 8 + // discriminator
@@ -86,7 +158,8 @@ impl GameAccount {
         8 + // round_end_time
         8 + // hit_a_lick_end_time (NEW)
         1 + // is_hit_a_lick_mode
-        8 + // _padding (alignment)
+        1 + // layout_version
+        7 + // _reserved (rest of legacy _padding)
         32 + // current_king
         8 + // king_since
         8 + // king_entry_price
@@ -108,7 +181,183 @@ impl GameAccount {
         2 + // min_growth_steals_for_war
         8 + // growth_hard_end_ts
         1 + // king_was_vip
-        1; // bump
+        1 + // bump
+        8 + // dust
+        8 + // game_id
+        StakeTier::SIZE + // tier
+        32 + // currency_mint
+        1 + // currency_decimals
+        8 + // season_number
+        8 + // round_season
+        8 + // claims_outstanding
+        8 + // round_start_ts
+        8 + // peak_price
+        1 + // war_trigger
+        1 + // has_round_history
+        8 + // war_start_ts
+        8 + // war_steals
+        8 + // round_volume
+        8 + // longest_reign_secs
+        32 + // longest_reign_wallet
+        4 + // unique_players
+        PLAYER_FILTER_BYTES + // player_filter
+        Ruleset::SIZE + // rules
+        8; // rules_version
+
+    /// Whether entries and payouts use an SPL mint instead of SOL
+    pub fn is_token_game(&self) -> bool {
+        self.currency_mint != Pubkey::default()
+    }
+
+    /// Balance the vault must keep on top of its liabilities (rent for SOL vaults;
+    /// a token vault's rent lives in its own token account)
+    pub fn vault_reserve(&self) -> u64 {
+        if self.is_token_game() {
+            0
+        } else {
+            RENT_EXEMPT_MIN
+        }
+    }
+
+    /// The fields `steal` prices and validates against (see logic::plan_steal)
+    pub fn steal_snapshot(&self) -> StealSnapshot {
+        StealSnapshot {
+            current_price: self.current_price,
+            hit_a_lick_price: self.hit_a_lick_price,
+            jackpot_balance: self.jackpot_balance,
+            yield_pool: self.yield_pool,
+            round_end_time: self.round_end_time,
+            hit_a_lick_end_time: self.hit_a_lick_end_time,
+            is_hit_a_lick_mode: self.is_hit_a_lick_mode,
+            has_king: self.has_king,
+            current_king: self.current_king,
+            king_since: self.king_since,
+            king_entry_price: self.king_entry_price,
+            king_was_vip: self.king_was_vip,
+            last_steal_wallet: self.last_steal_wallet,
+            last_steal_time: self.last_steal_time,
+            cooldown_seconds: self.cooldown_seconds,
+            growth_steals: self.growth_steals,
+            min_growth_steals_for_war: self.min_growth_steals_for_war,
+            tier: self.tier,
+            currency_decimals: self.currency_decimals,
+        }
+    }
+
+    /// Vault bookkeeping fields (see logic::book_steal / logic::settle_round)
+    pub fn ledger(&self) -> VaultLedger {
+        VaultLedger {
+            jackpot_balance: self.jackpot_balance,
+            pending_jackpot: self.pending_jackpot,
+            yield_pool: self.yield_pool,
+            claims_outstanding: self.claims_outstanding,
+            dust: self.dust,
+        }
+    }
+
+    /// Write back a ledger updated by logic::book_steal / logic::settle_round
+    pub fn set_ledger(&mut self, ledger: &VaultLedger) {
+        self.jackpot_balance = ledger.jackpot_balance;
+        self.pending_jackpot = ledger.pending_jackpot;
+        self.yield_pool = ledger.yield_pool;
+        self.claims_outstanding = ledger.claims_outstanding;
+        self.dust = ledger.dust;
+    }
+
+    /// Zero the per-round telemetry counters (at each round boundary)
+    pub fn clear_round_telemetry(&mut self) {
+        self.war_start_ts = 0;
+        self.war_steals = 0;
+        self.round_volume = 0;
+        self.longest_reign_secs = 0;
+        self.longest_reign_wallet = Pubkey::default();
+        self.unique_players = 0;
+        self.player_filter = [0; PLAYER_FILTER_BYTES];
+    }
+
+    /// Record a finished reign in the round telemetry if it is the longest so far
+    /// (a dethroned king, a refunded king, or the final king at settlement)
+    pub fn note_reign(&mut self, wallet: Pubkey, secs: u64) {
+        if secs > self.longest_reign_secs {
+            self.longest_reign_secs = secs;
+            self.longest_reign_wallet = wallet;
+        }
+    }
+
+    /// PDA seed suffix for this instance's game and vault PDAs
+    pub fn id_seed(&self) -> Vec<u8> {
+        game_id_seed(self.game_id)
+    }
+
+    /// Deserialize a game account written with any layout version, upgraded in memory
+    pub fn load_versioned(data: &[u8]) -> Result<Self> {
+        let mut game = Self::load_raw(data)?;
+        game.upgrade_layout();
+        Ok(game)
+    }
+
+    /// Deserialize a game account written with any layout version, as stored
+    /// Fields are only ever appended, so an older (shorter) account reads as if the missing
+    /// tail were zeroed; layout_version tells which of those fields are real
+    pub fn load_raw(data: &[u8]) -> Result<Self> {
+        let mut buf = data.to_vec();
+        if buf.len() < Self::SIZE {
+            buf.resize(Self::SIZE, 0);
+        }
+        Self::try_deserialize(&mut &buf[..])
+    }
+
+    /// Bring an in-memory game account up to GAME_LAYOUT_VERSION
+    /// Each step fills the fields introduced by that version
+    pub fn upgrade_layout(&mut self) {
+        if self.layout_version < 1 {
+            // v1: layout_version replaces the legacy _padding - nothing to fill
+            self._reserved = [0; 7];
+        }
+        if self.layout_version < 2 {
+            // v2: dust starts counting from the migration
+            self.dust = 0;
+        }
+        if self.layout_version < 3 {
+            // v3: only the original single-table game predates game ids
+            self.game_id = 0;
+        }
+        if self.layout_version < 4 {
+            // v4: the original table keeps the stakes it was hard-coded with
+            self.tier = StakeTier::default();
+        }
+        if self.layout_version < 5 {
+            // v5: everything before SPL-token games is a SOL game
+            self.currency_mint = Pubkey::default();
+            self.currency_decimals = SOL_DECIMALS;
+        }
+        if self.layout_version < 6 {
+            // v6: no seasons yet
+            self.season_number = 0;
+            self.round_season = 0;
+        }
+        if self.layout_version < 7 {
+            // v7: old kings were paid directly, nothing owed
+            self.claims_outstanding = 0;
+        }
+        if self.layout_version < 8 {
+            // v8: the running round's start and peak weren't tracked
+            self.round_start_ts = 0;
+            self.peak_price = 0;
+            self.war_trigger = 0;
+            self.has_round_history = false;
+        }
+        if self.layout_version < 9 {
+            // v9: round telemetry starts counting from the migration
+            self.clear_round_telemetry();
+        }
+        if self.layout_version < 10 {
+            // v10: no snapshot yet - migrate_game copies GameParams.active
+            self.rules = Ruleset::default();
+            self.rules_version = 0;
+        }
+        self.layout_version = GAME_LAYOUT_VERSION;
+    }
 }
 
 /// On-chain config account stored as PDA
@@ -125,6 +374,8 @@ pub struct GameConfig {
     pub steal_mint: Pubkey,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Authority proposed by `propose_authority` (default if none pending)
+    pub pending_authority: Pubkey,
 }
 
 #[cfg(any(not(test), feature = "idl-build"))]
@@ -135,12 +386,665 @@ impl GameConfig {
         32 + // dev_wallet
         32 + // beast_wallet
         32 + // steal_mint
+        1 + // bump
+        32; // pending_authority
+
+    /// Size of config accounts created before `pending_authority` was added
+    pub const LEGACY_SIZE: usize = Self::SIZE - 32;
+}
+
+/// Tunable economic ruleset (stored in GameParams, read by logic.rs)
+/// Permille values are out of 1000, rates/thresholds are percentages as in constants.rs
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ruleset {
+    /// Run It Up price multiplier in percent (112 = +12% per steal)
+    pub run_it_up_rate: u64,
+    /// Seconds added to the round timer per Run It Up steal
+    pub timer_add: u64,
+    /// Maximum round timer in seconds
+    pub max_timer: u64,
+    /// Run It Up surplus splits of profit_delta (must sum to 1000)
+    pub surplus_dev: u64,
+    pub surplus_beast: u64,
+    pub surplus_yield: u64,
+    pub surplus_jackpot: u64,
+    /// Price as percent of jackpot that triggers Hit A Lick
+    pub hit_a_lick_threshold: u64,
+    /// Hit A Lick price as permille of pot, clamped to the game's StakeTier
+    pub hit_a_lick_pct_of_pot: u64,
+    /// Hit A Lick timer in seconds (reset on each steal)
+    pub hit_a_lick_timer: u64,
+    /// Minimum seconds king must hold to win in Hit A Lick
+    pub min_hit_a_lick_hold: u64,
+    /// Per-wallet cooldown between steals in Hit A Lick
+    pub hit_a_lick_cooldown_secs: u64,
+    /// Hit A Lick surcharge for normies (permille)
+    pub hit_a_lick_surcharge: u64,
+    /// Hit A Lick overhead splits of hit_a_lick_price (sum must not exceed 1000)
+    pub hit_a_lick_old_king_refund: u64,
+    pub hit_a_lick_dev: u64,
+    pub hit_a_lick_beast: u64,
+    pub hit_a_lick_yield: u64,
+    pub hit_a_lick_jackpot: u64,
+    /// Normal round end payouts (sum must not exceed 1000)
+    pub end_winner_1: u64,
+    pub end_winner_2: u64,
+    pub end_winner_3: u64,
+    pub end_dev: u64,
+    pub end_beast: u64,
+    pub end_rollover: u64,
+    /// Mega round end payouts (sum must not exceed 1000)
+    pub end_winner_1_mega: u64,
+    pub end_winner_2_mega: u64,
+    pub end_winner_3_mega: u64,
+    pub end_dev_mega: u64,
+    pub end_beast_mega: u64,
+    pub end_rollover_mega: u64,
+    /// Yield base rate (permille of new payment)
+    pub yield_base_rate: u64,
+    /// Seconds of hold time for the full 2x time bonus
+    pub yield_time_bonus_cap_secs: u64,
+    /// Max yield as permille of entry price
+    pub yield_cap: u64,
+    /// Minimum yield paid to a dethroned king (covers tx fees)
+    pub min_yield: u64,
+    /// $STEAL balance required for VIP
+    pub token_requirement: u64,
+    /// Seconds a game must stay paused before emergency_refund can return the king's entry
+    pub emergency_refund_grace_secs: u64,
+}
+
+impl Ruleset {
+    /// Serialized size in bytes (36 u64 fields)
+    pub const SIZE: usize = 36 * 8;
+}
+
+impl Default for Ruleset {
+    /// The A+ ruleset from constants.rs
+    fn default() -> Self {
+        Self {
+            run_it_up_rate: RUN_IT_UP_RATE,
+            timer_add: TIMER_ADD,
+            max_timer: MAX_TIMER,
+            surplus_dev: SURPLUS_DEV,
+            surplus_beast: SURPLUS_BEAST,
+            surplus_yield: SURPLUS_YIELD,
+            surplus_jackpot: SURPLUS_JACKPOT,
+            hit_a_lick_threshold: HIT_A_LICK_THRESHOLD,
+            hit_a_lick_pct_of_pot: HIT_A_LICK_PCT_OF_POT,
+            hit_a_lick_timer: HIT_A_LICK_TIMER,
+            min_hit_a_lick_hold: MIN_HIT_A_LICK_HOLD,
+            hit_a_lick_cooldown_secs: HIT_A_LICK_COOLDOWN_SECS,
+            hit_a_lick_surcharge: HIT_A_LICK_SURCHARGE,
+            hit_a_lick_old_king_refund: HIT_A_LICK_OLD_KING_REFUND,
+            hit_a_lick_dev: HIT_A_LICK_DEV,
+            hit_a_lick_beast: HIT_A_LICK_BEAST,
+            hit_a_lick_yield: HIT_A_LICK_YIELD,
+            hit_a_lick_jackpot: HIT_A_LICK_JACKPOT,
+            end_winner_1: END_WINNER_1,
+            end_winner_2: END_WINNER_2,
+            end_winner_3: END_WINNER_3,
+            end_dev: END_DEV,
+            end_beast: END_BEAST,
+            end_rollover: END_ROLLOVER,
+            end_winner_1_mega: END_WINNER_1_MEGA,
+            end_winner_2_mega: END_WINNER_2_MEGA,
+            end_winner_3_mega: END_WINNER_3_MEGA,
+            end_dev_mega: END_DEV_MEGA,
+            end_beast_mega: END_BEAST_MEGA,
+            end_rollover_mega: END_ROLLOVER_MEGA,
+            yield_base_rate: YIELD_BASE_RATE,
+            yield_time_bonus_cap_secs: YIELD_TIME_BONUS_CAP_SECS,
+            yield_cap: YIELD_CAP,
+            min_yield: MIN_YIELD,
+            token_requirement: TOKEN_REQUIREMENT,
+            emergency_refund_grace_secs: EMERGENCY_REFUND_GRACE_SECS,
+        }
+    }
+}
+
+/// Per-table stakes, fixed when the game instance is created
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StakeTier {
+    /// Run It Up price at the start of each round
+    pub start_price: u64,
+    /// Hit A Lick price clamps
+    pub hit_a_lick_min: u64,
+    pub hit_a_lick_max: u64,
+    /// Pot size from which round end uses the mega payouts
+    pub mega_pot_threshold: u64,
+}
+
+impl StakeTier {
+    /// Serialized size in bytes (4 u64 fields)
+    pub const SIZE: usize = 4 * 8;
+}
+
+impl Default for StakeTier {
+    /// The original single-table stakes from constants.rs
+    fn default() -> Self {
+        Self {
+            start_price: START_PRICE,
+            hit_a_lick_min: HIT_A_LICK_MIN,
+            hit_a_lick_max: HIT_A_LICK_MAX,
+            mega_pot_threshold: MEGA_POT_THRESHOLD,
+        }
+    }
+}
+
+/// On-chain ruleset account stored as PDA
+/// set_params writes `staged`; it becomes `active` at the next round boundary
+#[cfg(any(not(test), feature = "idl-build"))]
+#[account]
+pub struct GameParams {
+    /// Version of the active ruleset (incremented each time a staged ruleset is applied)
+    pub version: u64,
+    /// Ruleset in force for the current round
+    pub active: Ruleset,
+    /// Ruleset waiting for the next round boundary
+    pub staged: Ruleset,
+    /// Whether `staged` holds a ruleset that has not been applied yet
+    pub has_staged: bool,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+impl GameParams {
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        8 + // version
+        Ruleset::SIZE + // active
+        Ruleset::SIZE + // staged
+        1 + // has_staged
         1; // bump
+
+    /// Promote the staged ruleset (called by end_round/reset_round only)
+    /// Returns true if a new ruleset took effect; each game picks it up at its own
+    /// round boundary through GameAccount::rules
+    pub fn apply_staged(&mut self) -> bool {
+        crate::logic::promote_staged_rules(&mut self.active, &self.staged, &mut self.has_staged, &mut self.version)
+    }
+}
+
+/// Emergency pause switch for a game, stored as PDA
+#[cfg(any(not(test), feature = "idl-build"))]
+#[account]
+pub struct CircuitBreaker {
+    /// Game this circuit breaker controls
+    pub game: Pubkey,
+    /// No new steals while set
+    pub steals_paused: bool,
+    /// No end_round/reset_round while set
+    pub settlements_paused: bool,
+    /// Timestamp when the game was paused (0 while running) - the game clock is frozen here
+    pub paused_at: i64,
+    /// Total seconds spent paused (lifetime)
+    pub total_paused_secs: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+impl CircuitBreaker {
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // game
+        1 + // steals_paused
+        1 + // settlements_paused
+        8 + // paused_at
+        8 + // total_paused_secs
+        1; // bump
+
+    /// Whether any pause flag is set
+    pub fn is_paused(&self) -> bool {
+        self.steals_paused || self.settlements_paused
+    }
+
+    /// Current time on the game clock (frozen at paused_at while paused)
+    pub fn game_clock(&self, unix_timestamp: i64) -> i64 {
+        crate::logic::game_clock(self.is_paused(), self.paused_at, unix_timestamp)
+    }
+}
+
+/// Scheduled competitive season of a game, stored as PDA
+/// Lamports held above rent are the season prize pool, paid out by close_season
+#[cfg(any(not(test), feature = "idl-build"))]
+#[account]
+pub struct Season {
+    /// Game this season belongs to
+    pub game: Pubkey,
+    /// Season number (1, 2, ...)
+    pub number: u64,
+    /// Steals are accepted from start_ts (inclusive)...
+    pub start_ts: i64,
+    /// ...until end_ts (exclusive)
+    pub end_ts: i64,
+    /// Lamports contributed to the season prize pool
+    pub prize_pool: u64,
+    /// Round in progress when the season was started
+    pub first_round: u64,
+    /// Reserved (0 - the account is closed by close_season)
+    pub last_round: u64,
+    /// Reserved (false - the account is closed by close_season)
+    pub closed: bool,
+    /// Reserved (0 - the account is closed by close_season)
+    pub closed_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+impl Season {
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // game
+        8 + // number
+        8 + // start_ts
+        8 + // end_ts
+        8 + // prize_pool
+        8 + // first_round
+        8 + // last_round
+        1 + // closed
+        8 + // closed_at
+        1; // bump
+}
+
+/// One ranked wallet on a leaderboard board (empty slots hold the default pubkey)
+#[zero_copy]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    pub wallet: Pubkey,
+    /// Board metric: net winnings (lamports or base units), steals, wars won or reign seconds
+    pub value: i64,
+}
+
+impl LeaderboardEntry {
+    /// Size in bytes
+    pub const SIZE: usize = 32 + 8;
+}
+
+/// Top wallets of one season, stored as a zero-copy PDA next to the Season
+/// Each board is kept sorted by value, highest first
+#[cfg(any(not(test), feature = "idl-build"))]
+#[account(zero_copy)]
+pub struct Leaderboard {
+    /// Game this leaderboard belongs to
+    pub game: Pubkey,
+    /// Season number the rounds were played in
+    pub season: u64,
+    /// Timestamp of the last update
+    pub updated_at: i64,
+    /// Payouts received less entry costs paid, booked as each reign resolves
+    pub net_winnings: [LeaderboardEntry; LEADERBOARD_SIZE],
+    /// Crowns stolen
+    pub steals: [LeaderboardEntry; LEADERBOARD_SIZE],
+    /// Hit A Lick rounds ended as king
+    pub wars_won: [LeaderboardEntry; LEADERBOARD_SIZE],
+    /// Longest single hold of the crown, in seconds
+    pub longest_reign: [LeaderboardEntry; LEADERBOARD_SIZE],
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Keeps the struct 8-byte aligned for zero-copy
+    pub _padding: [u8; 7],
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+impl Leaderboard {
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // game
+        8 + // season
+        8 + // updated_at
+        4 * LEADERBOARD_SIZE * LeaderboardEntry::SIZE + // boards
+        1 + // bump
+        7; // _padding
+}
+
+/// Balance a game's vault owes one wallet, stored as PDA
+/// Credited by `steal` (old king refund + yield) and withdrawn by `claim`
+#[cfg(any(not(test), feature = "idl-build"))]
+#[account]
+pub struct Claim {
+    /// Game whose vault holds the funds
+    pub game: Pubkey,
+    /// Wallet allowed to withdraw
+    pub wallet: Pubkey,
+    /// Currently claimable, in the game's entry currency
+    pub amount: u64,
+    /// Lifetime credited
+    pub total_credited: u64,
+    /// Lifetime withdrawn
+    pub total_claimed: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+impl Claim {
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // game
+        32 + // wallet
+        8 + // amount
+        8 + // total_credited
+        8 + // total_claimed
+        1; // bump
+}
+
+/// Podium payouts of one settled round, stored as PDA
+/// Recorded by `end_round`; each winner withdraws their place with `claim_prize`
+#[cfg(any(not(test), feature = "idl-build"))]
+#[account]
+pub struct RoundPrize {
+    /// Game whose vault holds the funds
+    pub game: Pubkey,
+    /// Round that was settled
+    pub round: u64,
+    /// 1st to 3rd place (1st is the final king)
+    pub winners: [Pubkey; 3],
+    /// Payout per place, in the game's entry currency (0 = no payout)
+    pub amounts: [u64; 3],
+    /// Places already withdrawn
+    pub claimed: [bool; 3],
+    /// Crank that paid the rent, refunded when the record is closed
+    pub rent_payer: Pubkey,
+    /// Timestamp of end_round
+    pub settled_at: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+impl RoundPrize {
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // game
+        8 + // round
+        (32 * 3) + // winners[3]
+        (8 * 3) + // amounts[3]
+        3 + // claimed[3]
+        32 + // rent_payer
+        8 + // settled_at
+        1; // bump
+}
+
+/// Result of one finished round in a RoundHistory (empty slots are all zero)
+#[zero_copy]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct RoundRecord {
+    /// Round number
+    pub round: u64,
+    /// Timestamp the round started (0 = unknown)
+    pub start_ts: i64,
+    /// Timestamp of end_round / reset_round
+    pub end_ts: i64,
+    /// Steals made during the round
+    pub total_steals: u64,
+    /// Highest entry cost paid during the round
+    pub peak_price: u64,
+    /// Jackpot at settlement, before payouts
+    pub jackpot_before: u64,
+    /// Jackpot carried into the next round
+    pub jackpot_after: u64,
+    /// Payout per place, in the game's entry currency (0 = no payout)
+    pub payouts: [u64; 3],
+    /// 1st to 3rd place (default pubkey = empty place)
+    pub winners: [Pubkey; 3],
+    /// 1 if the round reached Hit A Lick mode
+    pub war_reached: u8,
+    /// WAR_TRIGGER_* flags of what started the war
+    pub war_trigger: u8,
+    /// Keeps the struct 8-byte aligned for zero-copy
+    pub _padding: [u8; 6],
+}
+
+impl RoundRecord {
+    /// Size in bytes
+    pub const SIZE: usize = (8 * 7) + (8 * 3) + (32 * 3) + 1 + 1 + 6;
+}
+
+/// Last ROUND_HISTORY_SIZE finished rounds of a game, stored as a zero-copy PDA
+/// `records` is a ring buffer: round results land at `total_recorded % ROUND_HISTORY_SIZE`
+#[cfg(any(not(test), feature = "idl-build"))]
+#[account(zero_copy)]
+pub struct RoundHistory {
+    /// Game whose rounds are recorded
+    pub game: Pubkey,
+    /// Rounds appended since creation
+    pub total_recorded: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Keeps the struct 8-byte aligned for zero-copy
+    pub _padding: [u8; 7],
+    /// Ring buffer of round results
+    pub records: [RoundRecord; ROUND_HISTORY_SIZE],
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+impl RoundHistory {
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // game
+        8 + // total_recorded
+        1 + // bump
+        7 + // _padding
+        ROUND_HISTORY_SIZE * RoundRecord::SIZE; // records
+}
+
+/// Lifetime stats of one wallet across all game instances, stored as PDA
+/// Created by the wallet's first steal that passes it; lamport totals count SOL games only
+#[cfg(any(not(test), feature = "idl-build"))]
+#[account]
+pub struct PlayerAccount {
+    /// Wallet these stats belong to
+    pub wallet: Pubkey,
+    /// Crowns stolen
+    pub total_steals: u64,
+    /// Entry prices paid
+    pub lamports_spent: u64,
+    /// Entry refunds received as old king or dead-round king
+    pub refunds_received: u64,
+    /// Yield received on top of refunds
+    pub yield_received: u64,
+    /// Hit A Lick podium payouts received
+    pub prizes_received: u64,
+    /// Hit A Lick rounds finished 1st, 2nd and 3rd
+    pub war_wins: [u64; 3],
+    /// Consecutive rounds of the same game with at least one steal
+    pub current_streak: u64,
+    /// Game and round of the last steal
+    pub last_game: Pubkey,
+    pub last_round: u64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+impl PlayerAccount {
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // wallet
+        8 + // total_steals
+        8 + // lamports_spent
+        8 + // refunds_received
+        8 + // yield_received
+        8 + // prizes_received
+        (8 * 3) + // war_wins[3]
+        8 + // current_streak
+        32 + // last_game
+        8 + // last_round
+        1; // bump
+}
+
+/// Admin change recorded in a PendingChange account
+/// (account space is sized for the largest variant anyway, so no boxing)
+#[cfg(any(not(test), feature = "idl-build"))]
+#[allow(clippy::large_enum_variant)]
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// Rotate the dev wallet, beast wallet and STEAL mint
+    Config {
+        dev_wallet: Pubkey,
+        beast_wallet: Pubkey,
+        steal_mint: Pubkey,
+    },
+    /// Stage a new ruleset for the next round
+    Params {
+        rules: Ruleset,
+    },
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+impl ChangeKind {
+    /// Serialized size of the largest variant (1 byte tag + payload)
+    pub const SIZE: usize = 1 + Ruleset::SIZE;
+}
+
+/// Timelocked admin change stored as PDA (public queue for frontends/indexers)
+#[cfg(any(not(test), feature = "idl-build"))]
+#[account]
+pub struct PendingChange {
+    /// Caller-chosen id (PDA seed)
+    pub id: u64,
+    /// Authority that queued the change (receives rent back on execute)
+    pub proposer: Pubkey,
+    /// The proposed change
+    pub change: ChangeKind,
+    /// Timestamp when the change was queued
+    pub created_at: i64,
+    /// Earliest timestamp at which execute_change may apply it
+    pub eta: i64,
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+#[cfg(any(not(test), feature = "idl-build"))]
+impl PendingChange {
+    /// Size of the account in bytes
+    pub const SIZE: usize = 8 + // discriminator
+        8 + // id
+        32 + // proposer
+        ChangeKind::SIZE + // change
+        8 + // created_at
+        8 + // eta
+        1; // bump
+}
+
+/// Result of audit_vault (returned via set_return_data)
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VaultAudit {
+    /// Vault balance (lamports, or base units of the entry mint for token games)
+    pub vault_lamports: u64,
+    /// jackpot_balance + pending_jackpot + yield_pool + claims_outstanding + reserve
+    pub liabilities: u64,
+    /// Lamports not owed to anyone (0 if under-collateralized)
+    pub surplus: u64,
+    /// Lamports missing to cover liabilities (0 if solvent)
+    pub deficit: u64,
+}
+
+/// Where sweep_surplus sends unowed vault lamports
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SweepDestination {
+    /// Credit jackpot_balance (lamports stay in the vault)
+    Jackpot,
+    /// Transfer to config.dev_wallet
+    DevWallet,
+}
+
+/// Game fields a steal is priced and validated against (GameAccount::steal_snapshot)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StealSnapshot {
+    pub current_price: u64,
+    pub hit_a_lick_price: u64,
+    pub jackpot_balance: u64,
+    pub yield_pool: u64,
+    pub round_end_time: i64,
+    pub hit_a_lick_end_time: i64,
+    pub is_hit_a_lick_mode: bool,
+    pub has_king: bool,
+    pub current_king: Pubkey,
+    pub king_since: i64,
+    pub king_entry_price: u64,
+    pub king_was_vip: bool,
+    pub last_steal_wallet: Pubkey,
+    pub last_steal_time: i64,
+    pub cooldown_seconds: u64,
+    pub growth_steals: u16,
+    pub min_growth_steals_for_war: u16,
+    pub tier: StakeTier,
+    pub currency_decimals: u8,
+}
+
+/// Priced breakdown of one steal (computed by logic::plan_steal)
+/// `steal` executes it; `quote_steal` returns it via set_return_data
+#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StealQuote {
+    /// Round and mode the quote was made in (pass back as expected_round / expected_mode)
+    pub round: u64,
+    pub hit_a_lick_mode: bool,
+    /// Whether the player qualifies as VIP
+    pub is_vip: bool,
+    /// Amount the player pays (pass back as max_cost)
+    pub entry_cost: u64,
+    /// King being dethroned (default if none is paid)
+    pub old_king: Pubkey,
+    /// Credited to the old king's Claim account
+    pub old_king_refund: u64,
+    pub old_king_yield: u64,
+    /// Seconds the old king held the crown
+    pub old_king_reign: u64,
+    pub dev_cut: u64,
+    pub beast_cut: u64,
+    pub yield_pool_add: u64,
+    pub jackpot_add: u64,
+    /// Rounding dust, plus any reserved refund share the old king isn't owed, booked to the next jackpot
+    pub dust: u64,
+    /// Part of the old king's refund this entry doesn't cover, drawn from pending_jackpot
+    /// then jackpot_balance
+    /// entry_cost + refund_from_pot == old_king_refund + dev_cut + beast_cut + yield_pool_add + jackpot_add + dust
+    pub refund_from_pot: u64,
+    /// Run It Up price after this steal (unchanged in Hit A Lick mode)
+    pub next_price: u64,
+    /// Whether this steal flips the round into Hit A Lick mode, and why
+    pub triggers_hit_a_lick: bool,
+    pub price_trigger: bool,
+    pub steals_trigger: bool,
+    /// Hit A Lick price from then on (0 if not triggered)
+    pub hit_a_lick_price: u64,
+}
+
+/// Vault bookkeeping moved by steals and settlements (GameAccount::ledger)
+/// The sitting king's entry is not held: it already paid the previous king and the cuts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VaultLedger {
+    pub jackpot_balance: u64,
+    pub pending_jackpot: u64,
+    pub yield_pool: u64,
+    pub claims_outstanding: u64,
+    /// Lifetime rounding remainders (a counter, not a liability)
+    pub dust: u64,
+}
+
+/// Outcome of settling a round (computed by logic::settle_round)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RoundSettlement {
+    /// Jackpot at settlement, before payouts
+    pub total_pot: u64,
+    /// Claimable payout per place (1st is the final king)
+    pub payouts: [u64; 3],
+    /// Yield paid to the final king of a dead round (part of payouts[0])
+    pub yield_paid: u64,
+    /// Pot rolled into the next round (after any refund drawn from it), before this
+    /// round's pending jackpot is merged
+    pub rollover: u64,
+    pub mega: bool,
+    /// Rounding dust of the pot split (part of rollover)
+    pub dust: u64,
 }
 
 /// In-memory game state for logic calculations (used by tests)
-#[cfg(test)]
-use anchor_lang::prelude::Pubkey;
 
 #[derive(Debug, Clone, Default)]
 pub struct GameState {
@@ -172,4 +1076,8 @@ pub struct GameState {
     pub min_growth_steals_for_war: u16,
     /// Timestamp when growth phase must end (hard cap)
     pub growth_hard_end_ts: i64,
+    /// Lifetime rounding remainders from permille splits, routed to pending_jackpot
+    pub dust: u64,
+    /// Stakes for this table
+    pub tier: StakeTier,
 }
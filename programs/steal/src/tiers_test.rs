@@ -0,0 +1,81 @@
+// ============================================================================
+// STAKE TIER TESTS - per-table starting price, price clamps and mega pot
+// ============================================================================
+
+use crate::constants::*;
+use crate::errors::StealError;
+use crate::logic::*;
+use crate::state::{Ruleset, StakeTier};
+
+fn micro() -> StakeTier {
+    StakeTier {
+        start_price: 1_000_000,           // 0.001 SOL
+        hit_a_lick_min: 2_500_000,        // 0.0025 SOL
+        hit_a_lick_max: 75_000_000,       // 0.075 SOL
+        mega_pot_threshold: 2_500_000_000, // 2.5 SOL
+    }
+}
+
+fn high_roller() -> StakeTier {
+    StakeTier {
+        start_price: LAMPORTS_PER_SOL,
+        hit_a_lick_min: 2 * LAMPORTS_PER_SOL,
+        hit_a_lick_max: 75 * LAMPORTS_PER_SOL,
+        mega_pot_threshold: 2_500 * LAMPORTS_PER_SOL,
+    }
+}
+
+#[test]
+fn default_tier_matches_the_original_table() {
+    let tier = StakeTier::default();
+    assert!(validate_stake_tier(&tier).is_ok());
+    assert!(validate_stake_tier(&micro()).is_ok());
+    assert!(validate_stake_tier(&high_roller()).is_ok());
+    assert_eq!(new_game(&tier, &Ruleset::default()).current_price, START_PRICE);
+}
+
+#[test]
+fn invalid_tiers_rejected() {
+    let tier = StakeTier { start_price: 0, ..StakeTier::default() };
+    assert_eq!(validate_stake_tier(&tier).unwrap_err(), StealError::InvalidParams.into());
+
+    let tier = StakeTier { hit_a_lick_min: HIT_A_LICK_MAX + 1, ..StakeTier::default() };
+    assert_eq!(validate_stake_tier(&tier).unwrap_err(), StealError::InvalidParams.into());
+
+    let tier = StakeTier { mega_pot_threshold: 0, ..StakeTier::default() };
+    assert_eq!(validate_stake_tier(&tier).unwrap_err(), StealError::InvalidParams.into());
+}
+
+#[test]
+fn hit_a_lick_price_clamps_to_the_tier() {
+    let rules = Ruleset::default();
+    let pot = 10 * LAMPORTS_PER_SOL; // 3% = 0.3 SOL
+    assert_eq!(calculate_hit_a_lick_price(pot, &StakeTier::default(), &rules), 300_000_000);
+    assert_eq!(calculate_hit_a_lick_price(pot, &micro(), &rules), micro().hit_a_lick_max);
+    assert_eq!(calculate_hit_a_lick_price(pot, &high_roller(), &rules), high_roller().hit_a_lick_min);
+}
+
+#[test]
+fn mega_threshold_is_per_table() {
+    let rules = Ruleset::default();
+    let pot = 10 * LAMPORTS_PER_SOL;
+    assert!(is_mega_hit_a_lick(pot, &micro()));
+    assert!(!is_mega_hit_a_lick(pot, &StakeTier::default()));
+
+    let (w1_micro, ..) = calculate_hit_a_lick_end_payouts(pot, &micro(), &rules);
+    let (w1_default, ..) = calculate_hit_a_lick_end_payouts(pot, &StakeTier::default(), &rules);
+    assert_eq!(w1_micro, pot * rules.end_winner_1_mega / 1000);
+    assert_eq!(w1_default, pot * rules.end_winner_1 / 1000);
+}
+
+#[test]
+fn round_end_resets_to_the_tier_start_price() {
+    let rules = Ruleset::default();
+    let mut game = new_game(&micro(), &rules);
+    game.jackpot_balance = LAMPORTS_PER_SOL;
+    execute_steal(&mut game, &rules).unwrap();
+    assert!(game.current_price > micro().start_price);
+
+    execute_round_end(&mut game, &rules);
+    assert_eq!(game.current_price, micro().start_price);
+}
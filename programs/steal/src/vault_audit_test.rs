@@ -0,0 +1,194 @@
+// ============================================================================
+// VAULT AUDIT TESTS - liabilities, surplus/deficit reconciliation and sweeps
+// ============================================================================
+
+use anchor_lang::prelude::Pubkey;
+
+use crate::constants::*;
+use crate::logic::*;
+use crate::state::{RoundSettlement, Ruleset, StealQuote, StealSnapshot, VaultLedger};
+
+#[test]
+fn liabilities_include_pools_and_rent() {
+    let liabilities = calculate_vault_liabilities(1_000, 200, 30, 0, RENT_EXEMPT_MIN).unwrap();
+    assert_eq!(liabilities, 1_000 + 200 + 30 + RENT_EXEMPT_MIN);
+}
+
+#[test]
+fn unclaimed_balances_are_liabilities() {
+    let without = calculate_vault_liabilities(1_000, 0, 0, 0, RENT_EXEMPT_MIN).unwrap();
+    let with = calculate_vault_liabilities(1_000, 0, 0, 250, RENT_EXEMPT_MIN).unwrap();
+    assert_eq!(with - without, 250);
+    // A credited old king keeps the surplus at zero until they claim
+    assert_eq!(calculate_vault_surplus(without + 250, with), (0, 0));
+}
+
+#[test]
+fn liabilities_overflow_is_an_error() {
+    assert!(calculate_vault_liabilities(u64::MAX, 1, 0, 0, RENT_EXEMPT_MIN).is_err());
+}
+
+#[test]
+fn surplus_and_deficit_are_mutually_exclusive() {
+    assert_eq!(calculate_vault_surplus(1_500, 1_000), (500, 0));
+    assert_eq!(calculate_vault_surplus(800, 1_000), (0, 200));
+    assert_eq!(calculate_vault_surplus(1_000, 1_000), (0, 0));
+}
+
+#[test]
+fn freshly_seeded_vault_is_exactly_solvent() {
+    // initialize_game funds the vault with jackpot_seed + yield_seed + rent
+    let jackpot_seed = 5 * LAMPORTS_PER_SOL;
+    let yield_seed = LAMPORTS_PER_SOL / 2;
+    let vault = jackpot_seed + yield_seed + RENT_EXEMPT_MIN;
+    let liabilities = calculate_vault_liabilities(jackpot_seed, 0, yield_seed, 0, RENT_EXEMPT_MIN).unwrap();
+    assert_eq!(calculate_vault_surplus(vault, liabilities), (0, 0));
+}
+
+#[test]
+fn sweep_defaults_to_whole_surplus() {
+    let liabilities = calculate_vault_liabilities(1_000, 0, 0, 0, RENT_EXEMPT_MIN).unwrap();
+    let vault = liabilities + 37;
+    assert_eq!(calculate_sweep_amount(vault, liabilities, None).unwrap(), 37);
+    assert_eq!(calculate_sweep_amount(vault, liabilities, Some(10)).unwrap(), 10);
+}
+
+#[test]
+fn sweep_never_touches_liabilities() {
+    let liabilities = calculate_vault_liabilities(1_000, 50, 20, 300, RENT_EXEMPT_MIN).unwrap();
+    // One lamport over the surplus
+    assert!(calculate_sweep_amount(liabilities + 5, liabilities, Some(6)).is_err());
+    // Exactly solvent or under-collateralized - nothing to sweep
+    assert!(calculate_sweep_amount(liabilities, liabilities, None).is_err());
+    assert!(calculate_sweep_amount(liabilities - 1, liabilities, None).is_err());
+}
+
+/// A vault driven through the same plan_steal / book_steal / settle_round steps as
+/// `steal` and `end_round`, with the game fields those handlers update
+struct Table {
+    game: StealSnapshot,
+    ledger: VaultLedger,
+    recent_kings: Vec<Pubkey>,
+    vault: u64,
+    now: i64,
+}
+
+impl Table {
+    fn seeded(jackpot_seed: u64, yield_seed: u64) -> Self {
+        let game = StealSnapshot {
+            current_price: START_PRICE,
+            jackpot_balance: jackpot_seed,
+            yield_pool: yield_seed,
+            round_end_time: i64::MAX,
+            min_growth_steals_for_war: 6,
+            currency_decimals: SOL_DECIMALS,
+            ..StealSnapshot::default()
+        };
+        let ledger = VaultLedger { jackpot_balance: jackpot_seed, yield_pool: yield_seed, ..VaultLedger::default() };
+        Table { game, ledger, recent_kings: Vec::new(), vault: jackpot_seed + yield_seed, now: 1_000 }
+    }
+
+    fn steal(&mut self, player: Pubkey, rules: &Ruleset) -> StealQuote {
+        let quote = plan_steal(&self.game, 1, player, false, self.now, rules).unwrap();
+        // Entry in, dev and beast cuts out
+        self.vault = self.vault + quote.entry_cost - quote.dev_cut - quote.beast_cut;
+        book_steal(&mut self.ledger, &quote).unwrap();
+
+        let game = &mut self.game;
+        game.current_price = quote.next_price;
+        if !quote.hit_a_lick_mode {
+            game.growth_steals += 1;
+        }
+        if quote.triggers_hit_a_lick {
+            game.is_hit_a_lick_mode = true;
+            game.hit_a_lick_price = quote.hit_a_lick_price;
+            game.current_price = quote.hit_a_lick_price;
+            self.recent_kings.clear();
+        }
+        if game.is_hit_a_lick_mode {
+            self.recent_kings.retain(|k| *k != player);
+            self.recent_kings.insert(0, player);
+            self.recent_kings.truncate(3);
+        }
+        game.current_king = player;
+        game.has_king = true;
+        game.king_since = self.now;
+        game.king_entry_price = quote.entry_cost;
+        game.jackpot_balance = self.ledger.jackpot_balance;
+        game.yield_pool = self.ledger.yield_pool;
+        self.now += 5;
+        quote
+    }
+
+    fn end_round(&mut self, rules: &Ruleset) -> RoundSettlement {
+        settle_round(&self.game, self.recent_kings.len() as u8, self.now, rules, &mut self.ledger).unwrap()
+    }
+
+    /// Every lamport in the vault is owed to someone, and nothing owed is missing
+    fn assert_exactly_covered(&self, step: &str) {
+        let l = &self.ledger;
+        let liabilities = calculate_vault_liabilities(
+            l.jackpot_balance, l.pending_jackpot, l.yield_pool, l.claims_outstanding, 0,
+        ).unwrap();
+        assert_eq!(calculate_vault_surplus(self.vault, liabilities), (0, 0), "after {}", step);
+    }
+}
+
+#[test]
+fn vault_covers_liabilities_through_a_war_and_settlement() {
+    let rules = Ruleset::default();
+    let mut table = Table::seeded(10 * LAMPORTS_PER_SOL, LAMPORTS_PER_SOL / 2);
+    table.assert_exactly_covered("seeding");
+
+    // Run It Up until the steal count triggers the war, with a sitting king after each
+    let mut triggered = false;
+    for i in 0..6 {
+        triggered = table.steal(Pubkey::new_unique(), &rules).triggers_hit_a_lick;
+        table.assert_exactly_covered(&format!("run it up steal {}", i));
+    }
+    assert!(triggered);
+
+    // War steals: the first refunds the Run It Up price that triggered it
+    let regular = Pubkey::new_unique();
+    for (i, player) in [Pubkey::new_unique(), regular, Pubkey::new_unique(), regular].into_iter().enumerate() {
+        table.steal(player, &rules);
+        table.assert_exactly_covered(&format!("war steal {}", i));
+    }
+
+    let settlement = table.end_round(&rules);
+    assert!(settlement.payouts.iter().all(|p| *p > 0));
+    assert_eq!(table.ledger.pending_jackpot, 0);
+    table.assert_exactly_covered("end_round");
+}
+
+#[test]
+fn dead_round_refund_comes_out_of_the_pot() {
+    let rules = Ruleset::default();
+    let mut table = Table::seeded(10 * LAMPORTS_PER_SOL, LAMPORTS_PER_SOL / 2);
+    for _ in 0..3 {
+        table.steal(Pubkey::new_unique(), &rules);
+    }
+    assert!(!table.game.is_hit_a_lick_mode);
+    let king_entry = table.game.king_entry_price;
+    let pot = table.ledger.jackpot_balance + table.ledger.pending_jackpot;
+
+    let settlement = table.end_round(&rules);
+    assert_eq!(settlement.payouts[0], king_entry + settlement.yield_paid);
+    // The refund is paid once, by the pot - not on top of it
+    assert_eq!(table.ledger.jackpot_balance, pot - king_entry);
+    table.assert_exactly_covered("dead round end_round");
+}
+
+#[test]
+fn war_without_podium_refunds_the_king_from_the_pot() {
+    let rules = Ruleset::default();
+    let mut table = Table::seeded(10 * LAMPORTS_PER_SOL, LAMPORTS_PER_SOL / 2);
+    while !table.steal(Pubkey::new_unique(), &rules).triggers_hit_a_lick {}
+    // Defensive path: a war king missing from recent_kings gets their entry back instead
+    table.recent_kings.clear();
+    let king_entry = table.game.king_entry_price;
+
+    let settlement = table.end_round(&rules);
+    assert_eq!(settlement.payouts, [king_entry, 0, 0]);
+    table.assert_exactly_covered("podium-less war end_round");
+}
@@ -0,0 +1,102 @@
+// ============================================================================
+// VIP TESTS - $STEAL balance checks (Token and Token-2022 accounts)
+// ============================================================================
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_spl::token::{self, spl_token};
+use anchor_spl::token_2022::{self, spl_token_2022};
+use spl_token_2022::extension::immutable_owner::ImmutableOwner;
+use spl_token_2022::extension::transfer_fee::TransferFeeAmount;
+use spl_token_2022::extension::{BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut};
+use spl_token_2022::state::{Account, AccountState};
+
+use crate::errors::StealError;
+use crate::logic::*;
+use crate::state::Ruleset;
+
+#[test]
+fn frozen_accounts_do_not_count_toward_vip() {
+    let rules = Ruleset::default();
+    let balance = rules.token_requirement;
+    assert!(is_vip(vip_token_balance(balance, false), &rules));
+    assert!(!is_vip(vip_token_balance(balance, true), &rules));
+}
+
+#[test]
+fn vip_requires_the_full_token_requirement() {
+    let rules = Ruleset::default();
+    assert!(!is_vip(vip_token_balance(rules.token_requirement - 1, false), &rules));
+    assert!(is_vip(vip_token_balance(rules.token_requirement + 1, false), &rules));
+}
+
+/// Packed legacy Token account
+fn token_account(mint: Pubkey, owner: Pubkey, amount: u64) -> Vec<u8> {
+    let mut data = vec![0; spl_token::state::Account::LEN];
+    spl_token::state::Account {
+        mint,
+        owner,
+        amount,
+        state: spl_token::state::AccountState::Initialized,
+        ..Default::default()
+    }
+    .pack_into_slice(&mut data);
+    data
+}
+
+/// Token-2022 account carrying the extensions a transfer-fee mint gives its holders
+fn token_2022_account(mint: Pubkey, owner: Pubkey, amount: u64, state: AccountState) -> Vec<u8> {
+    let extensions = [ExtensionType::ImmutableOwner, ExtensionType::TransferFeeAmount];
+    let len = ExtensionType::try_calculate_account_len::<Account>(&extensions).unwrap();
+    let mut data = vec![0; len];
+    let mut account = StateWithExtensionsMut::<Account>::unpack_uninitialized(&mut data).unwrap();
+    account.base = Account { mint, owner, amount, state, ..Default::default() };
+    account.pack_base();
+    account.init_extension::<ImmutableOwner>(true).unwrap();
+    account.init_extension::<TransferFeeAmount>(true).unwrap();
+    account.init_account_type().unwrap();
+    data
+}
+
+#[test]
+fn extension_bearing_token_2022_account_counts() {
+    let (mint, player) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let data = token_2022_account(mint, player, 5_000, AccountState::Initialized);
+    assert!(data.len() > Account::LEN);
+
+    assert_eq!(vip_account_balance(&token_2022::ID, &data, &mint, &player).unwrap(), 5_000);
+    // Same holding in a legacy Token account
+    let legacy = token_account(mint, player, 5_000);
+    assert_eq!(vip_account_balance(&token::ID, &legacy, &mint, &player).unwrap(), 5_000);
+
+    // Frozen counts as empty
+    let frozen = token_2022_account(mint, player, 5_000, AccountState::Frozen);
+    assert_eq!(vip_account_balance(&token_2022::ID, &frozen, &mint, &player).unwrap(), 0);
+}
+
+#[test]
+fn wrong_owner_or_mint_is_rejected() {
+    let (mint, player) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let data = token_2022_account(mint, player, 5_000, AccountState::Initialized);
+
+    // Held by someone else
+    assert_eq!(
+        vip_account_balance(&token_2022::ID, &data, &mint, &Pubkey::new_unique()).unwrap_err(),
+        StealError::InvalidTokenAccountOwner.into()
+    );
+    // Another mint
+    assert_eq!(
+        vip_account_balance(&token_2022::ID, &data, &Pubkey::new_unique(), &player).unwrap_err(),
+        StealError::InvalidMint.into()
+    );
+    // Not owned by a token program
+    assert_eq!(
+        vip_account_balance(&Pubkey::new_unique(), &data, &mint, &player).unwrap_err(),
+        StealError::InvalidTokenAccount.into()
+    );
+    // Not a token account at all
+    assert_eq!(
+        vip_account_balance(&token::ID, &[0; 10], &mint, &player).unwrap_err(),
+        StealError::InvalidTokenAccount.into()
+    );
+}